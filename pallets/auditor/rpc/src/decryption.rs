@@ -0,0 +1,186 @@
+//! Node-side encryption and decryption of the content of encrypted audit logs.
+//!
+//! A reader keeps an ed25519 key of the `audx` key type in the keystore of its node, inserted
+//! with `author_insertKey`. The x25519 key pair of the reader is derived from the seed of that
+//! key, and its public key, given by [`encryption_key`], is the one to register on-chain with
+//! `register_encryption_key`.
+//!
+//! A reader rotating its key registers the key derived from a new keystore key, and keeps the
+//! previous one in the keystore to decrypt the audit logs sealed with it in the key epochs before.
+
+use chacha20poly1305::{
+	aead::{Aead, NewAead},
+	ChaCha20Poly1305, Key, Nonce,
+};
+use sc_keystore::LocalKeystore;
+use sp_core::{blake2_256, crypto::KeyTypeId, Pair};
+use x25519_dalek::{PublicKey, StaticSecret};
+
+/// Key type of the keys audit logs are decrypted with.
+pub const AUDITOR_ENCRYPTION: KeyTypeId = KeyTypeId(*b"audx");
+
+/// Keys of the readers of encrypted audit logs, as kept in the keystore.
+pub mod app {
+	use sp_application_crypto::{app_crypto, ed25519};
+	app_crypto!(ed25519, super::AUDITOR_ENCRYPTION);
+}
+
+// Context the x25519 secret key of a reader is derived with
+const SECRET_KEY_CONTEXT: &[u8] = b"auditor-x25519";
+// Context the key sealing a content key for a reader is derived with
+const KEY_WRAP_CONTEXT: &[u8] = b"auditor-key-wrap";
+
+/// Why the content of an audit log can't be decrypted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecryptionError {
+	/// The keystore has no key for the reader.
+	KeyNotFound,
+	/// The keystore could not be read.
+	Keystore(String),
+	/// The content key sealed for the reader is not sealed with its encryption key.
+	InvalidWrappedKey,
+	/// The content is not encrypted with the content key, or has been altered.
+	InvalidCiphertext,
+}
+
+/// Returns the x25519 public key of the reader, to be registered on-chain.
+pub fn encryption_key(keystore: &LocalKeystore, reader: &app::Public) -> Result<[u8; 32], DecryptionError> {
+	let secret_key = secret_key(keystore, reader)?;
+	Ok(PublicKey::from(&secret_key).to_bytes())
+}
+
+/// Decrypts the content of an audit log with the content key sealed for the reader, the reader
+/// key being read from the keystore.
+pub fn decrypt_content(
+	keystore: &LocalKeystore,
+	reader: &app::Public,
+	ephemeral_public_key: [u8; 32],
+	nonce: [u8; 12],
+	wrapped_key: [u8; 48],
+	ciphertext: &[u8],
+) -> Result<Vec<u8>, DecryptionError> {
+	let secret_key = secret_key(keystore, reader)?;
+	let encryption_key = PublicKey::from(&secret_key).to_bytes();
+	let shared_secret = secret_key.diffie_hellman(&PublicKey::from(ephemeral_public_key));
+
+	let wrapping_key = wrapping_key(shared_secret.as_bytes(), &ephemeral_public_key, &encryption_key);
+	let content_key = ChaCha20Poly1305::new(Key::from_slice(&wrapping_key))
+		.decrypt(Nonce::from_slice(&[0u8; 12]), &wrapped_key[..])
+		.map_err(|_| DecryptionError::InvalidWrappedKey)?;
+
+	ChaCha20Poly1305::new(Key::from_slice(&content_key))
+		.decrypt(Nonce::from_slice(&nonce), ciphertext)
+		.map_err(|_| DecryptionError::InvalidCiphertext)
+}
+
+/// Encrypts the content under the content key and seals the content key for every encryption
+/// key, in the same order. Returns the ciphertext, the ephemeral public key and the sealed
+/// content keys, as expected by `save_encrypted_audit_log`.
+///
+/// The content key, the nonce and the ephemeral secret key must be freshly random for every
+/// audit log.
+pub fn encrypt_content(
+	content: &[u8],
+	content_key: [u8; 32],
+	nonce: [u8; 12],
+	ephemeral_secret_key: [u8; 32],
+	encryption_keys: &[[u8; 32]],
+) -> (Vec<u8>, [u8; 32], Vec<[u8; 48]>) {
+	let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&content_key))
+		.encrypt(Nonce::from_slice(&nonce), content)
+		.expect("content fits in a ChaCha20-Poly1305 message; qed");
+
+	let ephemeral_secret_key = StaticSecret::from(ephemeral_secret_key);
+	let ephemeral_public_key = PublicKey::from(&ephemeral_secret_key).to_bytes();
+	let wrapped_keys = encryption_keys
+		.iter()
+		.map(|encryption_key| {
+			let shared_secret = ephemeral_secret_key.diffie_hellman(&PublicKey::from(*encryption_key));
+			let wrapping_key = wrapping_key(shared_secret.as_bytes(), &ephemeral_public_key, encryption_key);
+			let wrapped_key = ChaCha20Poly1305::new(Key::from_slice(&wrapping_key))
+				.encrypt(Nonce::from_slice(&[0u8; 12]), &content_key[..])
+				.expect("a content key fits in a ChaCha20-Poly1305 message; qed");
+			let mut sealed = [0u8; 48];
+			sealed.copy_from_slice(&wrapped_key);
+			sealed
+		})
+		.collect();
+
+	(ciphertext, ephemeral_public_key, wrapped_keys)
+}
+
+fn secret_key(keystore: &LocalKeystore, reader: &app::Public) -> Result<StaticSecret, DecryptionError> {
+	let pair = keystore
+		.key_pair::<app::Pair>(reader)
+		.map_err(|error| DecryptionError::Keystore(error.to_string()))?
+		.ok_or(DecryptionError::KeyNotFound)?;
+	let seed = pair.to_raw_vec();
+	Ok(StaticSecret::from(blake2_256(&[SECRET_KEY_CONTEXT, &seed[..]].concat())))
+}
+
+fn wrapping_key(shared_secret: &[u8; 32], ephemeral_public_key: &[u8; 32], encryption_key: &[u8; 32]) -> [u8; 32] {
+	blake2_256(&[KEY_WRAP_CONTEXT, &shared_secret[..], &ephemeral_public_key[..], &encryption_key[..]].concat())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_keystore::SyncCryptoStore;
+
+	// Generates a reader key in the keystore
+	fn new_reader(keystore: &LocalKeystore) -> app::Public {
+		app::Public::from(SyncCryptoStore::ed25519_generate_new(keystore, AUDITOR_ENCRYPTION, None).unwrap())
+	}
+
+	// Encrypts the content for the reader with fixed keys and nonce, returning the ciphertext, the ephemeral public key
+	// and the content key sealed for the reader
+	fn encrypt_for(keystore: &LocalKeystore, reader: &app::Public, content: &[u8]) -> (Vec<u8>, [u8; 32], [u8; 48]) {
+		let encryption_key = encryption_key(keystore, reader).unwrap();
+		let (ciphertext, ephemeral_public_key, wrapped_keys) = encrypt_content(content, [1u8; 32], [2u8; 12], [3u8; 32], &[encryption_key]);
+		(ciphertext, ephemeral_public_key, wrapped_keys[0])
+	}
+
+	#[test]
+	fn reader_decrypts_the_content() {
+		let keystore = LocalKeystore::in_memory();
+		let reader = new_reader(&keystore);
+		let (ciphertext, ephemeral_public_key, wrapped_key) = encrypt_for(&keystore, &reader, b"content");
+
+		assert_ne!(ciphertext, b"content".to_vec());
+		assert_eq!(decrypt_content(&keystore, &reader, ephemeral_public_key, [2u8; 12], wrapped_key, &ciphertext), Ok(b"content".to_vec()));
+	}
+
+	#[test]
+	fn other_reader_cannot_unwrap_the_content_key() {
+		let keystore = LocalKeystore::in_memory();
+		let reader = new_reader(&keystore);
+		let other_reader = new_reader(&keystore);
+		let (ciphertext, ephemeral_public_key, wrapped_key) = encrypt_for(&keystore, &reader, b"content");
+
+		assert_eq!(
+			decrypt_content(&keystore, &other_reader, ephemeral_public_key, [2u8; 12], wrapped_key, &ciphertext),
+			Err(DecryptionError::InvalidWrappedKey)
+		);
+	}
+
+	#[test]
+	fn tampered_ciphertext_is_rejected() {
+		let keystore = LocalKeystore::in_memory();
+		let reader = new_reader(&keystore);
+		let (mut ciphertext, ephemeral_public_key, wrapped_key) = encrypt_for(&keystore, &reader, b"content");
+		ciphertext[0] ^= 1;
+
+		assert_eq!(
+			decrypt_content(&keystore, &reader, ephemeral_public_key, [2u8; 12], wrapped_key, &ciphertext),
+			Err(DecryptionError::InvalidCiphertext)
+		);
+	}
+
+	#[test]
+	fn reader_key_missing_from_the_keystore_is_reported() {
+		let keystore = LocalKeystore::in_memory();
+		let reader = new_reader(&LocalKeystore::in_memory());
+
+		assert_eq!(encryption_key(&keystore, &reader), Err(DecryptionError::KeyNotFound));
+	}
+}
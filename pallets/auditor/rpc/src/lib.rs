@@ -0,0 +1,175 @@
+//! RPC interface for the auditor pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub mod decryption;
+
+pub use auditor_runtime_api::AuditorApi as AuditorRuntimeApi;
+
+/// Proof that an audit log is part of the Merkle tree of its log file and day at a block.
+///
+/// Checked with `auditor_pallet::merkle::verify_merkle_proof` against the root, which can itself
+/// be checked against the state of the block.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryProof<Hash> {
+	/// Block the proof was built at.
+	pub block_hash: Hash,
+	/// Chain hash of the audit log.
+	pub leaf: Hash,
+	/// Index of the audit log within its day.
+	pub leaf_index: u32,
+	/// Sibling path of the leaf, from the leaf up to the root.
+	pub siblings: Vec<Hash>,
+	/// Merkle root of the log file and day.
+	pub root: Hash,
+}
+
+/// Auditor RPC methods.
+#[rpc]
+pub trait AuditorApi<BlockHash, AccountId> {
+	/// Checks whether the payload is the content of the audit log at the index of the day,
+	/// also for audit logs only keeping the digest of their content.
+	#[rpc(name = "auditor_verifyContent")]
+	fn verify_content(
+		&self,
+		log_file_name: Bytes,
+		log_day: u32,
+		entry_index: u32,
+		payload: Bytes,
+		at: Option<BlockHash>,
+	) -> Result<bool>;
+
+	/// Returns the Merkle inclusion proof of the audit log at the index of the day, or `None`
+	/// when there is no such audit log.
+	#[rpc(name = "auditor_getEntryProof")]
+	fn get_entry_proof(
+		&self,
+		log_file_name: Bytes,
+		log_day: u32,
+		entry_index: u32,
+		at: Option<BlockHash>,
+	) -> Result<Option<EntryProof<BlockHash>>>;
+
+	/// Returns how the reporter is shown, its system name followed by its environment such as
+	/// `payments-api (prod)`, or `None` when it is not registered.
+	#[rpc(name = "auditor_getReporterLabel")]
+	fn get_reporter_label(&self, reporter: AccountId, at: Option<BlockHash>) -> Result<Option<String>>;
+
+	/// Returns the audit logs the reporter can still save on the log file in the next block and
+	/// the bytes it can still save in the rate limit window of the next block, `None` meaning
+	/// unlimited.
+	#[rpc(name = "auditor_getRemainingRateQuota")]
+	fn get_remaining_rate_quota(
+		&self,
+		log_file_name: Bytes,
+		reporter: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<(Option<u32>, Option<u32>)>;
+}
+
+/// Error code returned when the runtime could not be called.
+const RUNTIME_ERROR: i64 = 1;
+
+/// Implements the auditor RPC methods on top of the runtime API.
+pub struct Auditor<C, Block, AuditLog, Severity, LogSchema, AccountId> {
+	client: Arc<C>,
+	_marker: PhantomData<(Block, AuditLog, Severity, LogSchema, AccountId)>,
+}
+
+impl<C, Block, AuditLog, Severity, LogSchema, AccountId> Auditor<C, Block, AuditLog, Severity, LogSchema, AccountId> {
+	/// Creates a new instance of the auditor RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AuditLog, Severity, LogSchema, AccountId> AuditorApi<<Block as BlockT>::Hash, AccountId>
+	for Auditor<C, Block, AuditLog, Severity, LogSchema, AccountId>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AuditorRuntimeApi<Block, AuditLog, <Block as BlockT>::Hash, Severity, LogSchema, AccountId>,
+	AuditLog: Codec + Send + Sync + 'static,
+	Severity: Codec + Send + Sync + 'static,
+	LogSchema: Codec + Send + Sync + 'static,
+	AccountId: Codec + Send + Sync + 'static,
+{
+	fn verify_content(
+		&self,
+		log_file_name: Bytes,
+		log_day: u32,
+		entry_index: u32,
+		payload: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<bool> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.verify_audit_log_content(&at, log_file_name.to_vec(), log_day, entry_index, payload.to_vec())
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(RUNTIME_ERROR),
+				message: "Unable to verify the audit log content.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn get_entry_proof(
+		&self,
+		log_file_name: Bytes,
+		log_day: u32,
+		entry_index: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<EntryProof<<Block as BlockT>::Hash>>> {
+		let api = self.client.runtime_api();
+		let block_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let proof = api
+			.audit_log_entry_proof(&BlockId::hash(block_hash), log_file_name.to_vec(), log_day, entry_index)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(RUNTIME_ERROR),
+				message: "Unable to build the audit log entry proof.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})?;
+
+		Ok(proof.map(|(leaf, siblings, root)| EntryProof { block_hash, leaf, leaf_index: entry_index, siblings, root }))
+	}
+
+	fn get_reporter_label(&self, reporter: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<String>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let label = api.reporter_label(&at, reporter).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(RUNTIME_ERROR),
+			message: "Unable to read the reporter.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+
+		Ok(label.map(|label| String::from_utf8_lossy(&label).into_owned()))
+	}
+
+	fn get_remaining_rate_quota(
+		&self,
+		log_file_name: Bytes,
+		reporter: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<(Option<u32>, Option<u32>)> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.remaining_rate_quota(&at, log_file_name.to_vec(), reporter).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(RUNTIME_ERROR),
+			message: "Unable to read the remaining rate quota.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
@@ -0,0 +1,70 @@
+//! Runtime API definition for the auditor pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Reads audit logs without going through raw storage queries.
+	pub trait AuditorApi<AuditLog, Hash, Severity, LogSchema, AccountId> where
+		AuditLog: Codec,
+		Hash: Codec,
+		Severity: Codec,
+		LogSchema: Codec,
+		AccountId: Codec,
+	{
+		/// Returns every audit log saved for a log file on a day, oldest first.
+		///
+		/// Days are counted since the unix epoch, from the timestamp of the block including the
+		/// audit log.
+		fn retrieve_audit_logs(log_file_name: Vec<u8>, log_day: u32) -> Vec<AuditLog>;
+
+		/// Returns the audit logs saved for a log file on a day that are at least as severe as
+		/// `min_severity`, have the `tag` and have the key/value `attribute`, each filter being
+		/// optional.
+		fn retrieve_audit_logs_filtered(
+			log_file_name: Vec<u8>,
+			log_day: u32,
+			min_severity: Option<Severity>,
+			tag: Option<Vec<u8>>,
+			attribute: Option<(Vec<u8>, Vec<u8>)>,
+		) -> Vec<AuditLog>;
+
+		/// Checks a payload against the content, or the content digest, of the audit log at the
+		/// index of the day.
+		fn verify_audit_log_content(log_file_name: Vec<u8>, log_day: u32, entry_index: u32, payload: Vec<u8>) -> bool;
+
+		/// Returns the audit logs of a log file from one sequence of its hash chain to another,
+		/// both included, for exporting them.
+		fn retrieve_audit_log_chain(log_file_name: Vec<u8>, from: u64, to: u64) -> Vec<AuditLog>;
+
+		/// Checks that the audit logs of a log file from one sequence of its hash chain to
+		/// another, both included, are unaltered and linked to the audit logs around them.
+		fn verify_chain(log_file_name: Vec<u8>, from: u64, to: u64) -> bool;
+
+		/// Returns the leaf of the audit log at the index of the day in the Merkle tree of the
+		/// day, its sibling path from the leaf up and the Merkle root of the day.
+		fn audit_log_entry_proof(log_file_name: Vec<u8>, log_day: u32, entry_index: u32) -> Option<(Hash, Vec<Hash>, Hash)>;
+
+		/// Returns the schema the audit logs saved on a log file must follow, if any.
+		fn retrieve_log_schema(log_file_name: Vec<u8>) -> Option<LogSchema>;
+
+		/// Returns the log files and days of the audit logs due to be pruned within the given
+		/// number of days, along with the day they are due on, the soonest first.
+		fn audit_logs_due_for_pruning(within_days: u32) -> Vec<(Vec<u8>, u32, u32)>;
+
+		/// Returns how query tools show the reporter, its system name followed by its
+		/// environment such as `payments-api (prod)`, if it is registered.
+		fn reporter_label(reporter: AccountId) -> Option<Vec<u8>>;
+
+		/// Returns the audit logs the reporter can still save on the log file in the next block
+		/// and the bytes it can still save in the rate limit window of the next block, under the limits of
+		/// both the log file and the reporter. `None` means unlimited.
+		fn remaining_rate_quota(log_file_name: Vec<u8>, reporter: AccountId) -> (Option<u32>, Option<u32>);
+
+		/// Returns the number of days a log file has audit logs for, to give as the witness of the
+		/// calls rescheduling their pruning.
+		fn log_day_count(log_file_name: Vec<u8>) -> u32;
+	}
+}
@@ -3,18 +3,386 @@
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use crate::Pallet as Auditor;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::sp_runtime::traits::Bounded;
+use frame_support::traits::EnsureOrigin;
 use frame_system::RawOrigin;
 
+const SEED: u32 = 0;
+
+// Gives the account enough balance to reserve the deposits of its audit logs
+fn funded<T: Config>(account: T::AccountId) -> T::AccountId {
+	T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value() / 2u32.into());
+	account
+}
+
+// Saves an audit log on the log file, the sender becoming its owner when it is the first one
+fn save_audit_log<T: Config>(sender: &T::AccountId, log_file_name: &[u8], content: Vec<u8>) -> DispatchResult {
+	Auditor::<T>::save_audit_log(
+		RawOrigin::Signed(sender.clone()).into(),
+		log_file_name.to_vec(),
+		Some(b"2021-10-08".to_vec()),
+		b"log-title".to_vec(),
+		content,
+		b"2021-10-08 17:30:00 UTC".to_vec(),
+	)
+	.map(|_| ())
+	.map_err(|e| e.error)
+}
+
+// Makes other accounts admins of the log file until it has the number of owners
+fn add_owners<T: Config>(owner: &T::AccountId, log_file_name: &[u8], owners: u32) -> DispatchResult {
+	for i in 1..owners {
+		Auditor::<T>::grant_log_role(RawOrigin::Signed(owner.clone()).into(), log_file_name.to_vec(), account("owner", i, SEED), AuditLogRole::Admin)?;
+	}
+	Ok(())
+}
+
+// Saves an audit log on the log file on each of the days, from the first one
+fn save_audit_logs_on_days<T: Config>(sender: &T::AccountId, log_file_name: &[u8], days: u32) -> DispatchResult {
+	for day in 0..days {
+		pallet_timestamp::Pallet::<T>::set_timestamp((day as u64 * MILLISECS_PER_DAY).saturated_into());
+		save_audit_log::<T>(sender, log_file_name, Vec::new())?;
+	}
+	Ok(())
+}
+
+// Registers the account as a reporter, with fields as long as they can be
+fn register_reporter<T: Config>(reporter: &T::AccountId) -> DispatchResult {
+	let field = frame_support::sp_std::vec![b'a'; T::MaxReporterFieldLen::get() as usize];
+	Auditor::<T>::register_reporter(RawOrigin::Signed(reporter.clone()).into(), field.clone(), field.clone(), field, Default::default())
+}
+
+// Splits the bytes of an audit log between its title, up to its bound, and its content
+fn title_and_content<T: Config>(b: u32) -> (Vec<u8>, Vec<u8>) {
+	let title_len = b.min(T::MaxTitleLen::get());
+	(frame_support::sp_std::vec![b'a'; title_len as usize], frame_support::sp_std::vec![0u8; (b - title_len) as usize])
+}
+
+// Raw public key that decodes into the account
+fn pubkey_of<T: Config>(account: &T::AccountId) -> [u8; 32] {
+	let encoded = account.encode();
+	let mut pubkey = [0u8; 32];
+	pubkey[..encoded.len()].copy_from_slice(&encoded);
+	pubkey
+}
+
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+	save_audit_log {
+		// Bytes of the title and content of the audit log
+		let b in 0 .. T::MaxTitleLen::get() + T::MaxContentLen::get();
+		let caller = funded::<T>(whitelisted_caller());
+		let (title, content) = title_and_content::<T>(b);
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), Some(b"2021-10-08".to_vec()), title, content, b"2021-10-08 17:30:00 UTC".to_vec())
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert_eq!(AuditLogChainHeadStorage::<T>::get(log_file_name).get_length(), 1);
+	}
+
+	register_encryption_key {
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), [1u8; 32])
+	verify {
+		assert_eq!(AuditLogEncryptionKeyStorage::<T>::get(&caller), Some([1u8; 32]));
+	}
+
+	start_key_epoch {
+		let r in 1 .. T::MaxReaders::get();
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		let readers = (0..r).map(|i| account("reader", i, SEED)).collect::<Vec<T::AccountId>>();
+		for reader in readers.iter() {
+			Auditor::<T>::register_encryption_key(RawOrigin::Signed(reader.clone()).into(), [1u8; 32])?;
+		}
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), readers)
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(AuditLogKeyEpochStorage::<T>::contains_key(&log_file_name));
+	}
+
+	register_reporter {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+		let field = frame_support::sp_std::vec![b'a'; T::MaxReporterFieldLen::get() as usize];
+	}: _(RawOrigin::Signed(caller.clone()), field.clone(), field.clone(), field, Default::default())
+	verify {
+		assert!(AuditLogReporterStorage::<T>::contains_key(&caller));
+	}
+
+	update_reporter {
+		let caller: T::AccountId = whitelisted_caller();
+		register_reporter::<T>(&caller)?;
+		let field = frame_support::sp_std::vec![b'b'; T::MaxReporterFieldLen::get() as usize];
+	}: _(RawOrigin::Signed(caller.clone()), field.clone(), field.clone(), field, Default::default())
+	verify {
+		assert!(AuditLogReporterStorage::<T>::contains_key(&caller));
+	}
+
+	retire_reporter {
+		let caller: T::AccountId = whitelisted_caller();
+		register_reporter::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Auditor::<T>::reporter_label(caller).is_some());
+	}
+
+	set_registered_reporters_only {
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), true)
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(AuditLogRegisteredReportersOnlyStorage::<T>::get(&log_file_name));
+	}
+
+	authorize_delegate {
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), delegate.clone(), Some(100u32.into()), Some(10))
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(AuditLogDelegationStorage::<T>::contains_key(&log_file_name, &delegate));
+	}
+
+	revoke_delegate {
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		Auditor::<T>::authorize_delegate(RawOrigin::Signed(caller.clone()).into(), b"log-file-name".to_vec(), delegate.clone(), None, None)?;
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), delegate.clone())
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(!AuditLogDelegationStorage::<T>::contains_key(&log_file_name, &delegate));
+	}
+
+	set_log_rate_limit {
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		let reporter: T::AccountId = account("reporter", 0, SEED);
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), Some(reporter.clone()), Some(AuditLogRateLimit::new(Some(1), Some(1))))
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(AuditLogReporterRateLimitStorage::<T>::contains_key(&log_file_name, &reporter));
+	}
+
+	claim_log {
+		// Owners of the log file before the claimer joins them
+		let o in 1 .. T::MaxOwners::get() - 1;
+		let owner = funded::<T>(account("owner", 0, SEED));
+		save_audit_log::<T>(&owner, b"log-file-name", Vec::new())?;
+		add_owners::<T>(&owner, b"log-file-name", o)?;
+		let claimer: T::AccountId = whitelisted_caller();
+		Auditor::<T>::open_log_for_ownership_claim(RawOrigin::Signed(owner).into(), b"log-file-name".to_vec(), pubkey_of::<T>(&claimer))?;
+	}: _(RawOrigin::Signed(claimer), b"log-file-name".to_vec())
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(!AuditLogOpenForClaimStorage::<T>::contains_key(&log_file_name));
+		assert_eq!(AuditLogRoleStorage::<T>::get(&log_file_name).len() as u32, o + 1);
+	}
+
+	open_log_for_ownership_claim {
+		let o in 1 .. T::MaxOwners::get();
+		// Claims of other log files closing in the same block
+		let c in 0 .. T::MaxClaimsExpiringPerBlock::get() - 1;
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		add_owners::<T>(&caller, b"log-file-name", o)?;
+		for i in 0..c {
+			save_audit_log::<T>(&caller, &i.to_le_bytes(), Vec::new())?;
+			Auditor::<T>::open_log_for_ownership_claim(RawOrigin::Signed(caller.clone()).into(), i.to_le_bytes().to_vec(), [0u8; 32])?;
+		}
+		let claimer: T::AccountId = account("claimer", 0, SEED);
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), pubkey_of::<T>(&claimer))
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(AuditLogOpenForClaimStorage::<T>::contains_key(&log_file_name));
+	}
+
+	cancel_ownership_claim {
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		Auditor::<T>::open_log_for_ownership_claim(RawOrigin::Signed(caller.clone()).into(), b"log-file-name".to_vec(), [0u8; 32])?;
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec())
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(!AuditLogOpenForClaimStorage::<T>::contains_key(&log_file_name));
+	}
+
+	replace_ownership_claim {
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		Auditor::<T>::open_log_for_ownership_claim(RawOrigin::Signed(caller.clone()).into(), b"log-file-name".to_vec(), [0u8; 32])?;
+		let claimer: T::AccountId = account("claimer", 0, SEED);
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), pubkey_of::<T>(&claimer))
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert_eq!(AuditLogOpenForClaimStorage::<T>::get(&log_file_name).get_assigned_claimer(), claimer);
+	}
+
+	set_log_schema {
+		// Attributes every audit log of the log file must have
+		let a in 0 .. T::MaxAttributes::get();
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		let required_attribute_keys = (0..a).map(|i| i.to_le_bytes().to_vec()).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), required_attribute_keys, Vec::new(), None, AuditLogContentType::Any)
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(AuditLogSchemaStorage::<T>::contains_key(&log_file_name));
+	}
+
+	clear_log_schema {
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		Auditor::<T>::set_log_schema(RawOrigin::Signed(caller.clone()).into(), b"log-file-name".to_vec(), Vec::new(), Vec::new(), None, AuditLogContentType::Any)?;
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec())
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(!AuditLogSchemaStorage::<T>::contains_key(&log_file_name));
+	}
+
+	grant_log_role {
+		// Members of the log file before the account joins them
+		let o in 1 .. T::MaxOwners::get() - 1;
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		add_owners::<T>(&caller, b"log-file-name", o)?;
+		let writer: T::AccountId = account("writer", 0, SEED);
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), writer, AuditLogRole::Writer)
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert_eq!(AuditLogRoleStorage::<T>::get(&log_file_name).len() as u32, o + 1);
+	}
+
+	revoke_log_role {
+		let o in 1 .. T::MaxOwners::get() - 1;
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		add_owners::<T>(&caller, b"log-file-name", o)?;
+		let writer: T::AccountId = account("writer", 0, SEED);
+		Auditor::<T>::grant_log_role(RawOrigin::Signed(caller.clone()).into(), b"log-file-name".to_vec(), writer.clone(), AuditLogRole::Writer)?;
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), writer)
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert_eq!(AuditLogRoleStorage::<T>::get(&log_file_name).len() as u32, o);
+	}
+
+	remove_owner {
+		let o in 2 .. T::MaxOwners::get();
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		add_owners::<T>(&caller, b"log-file-name", o)?;
+		let owner: T::AccountId = account("owner", 1, SEED);
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), owner)
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert_eq!(AuditLogRoleStorage::<T>::get(&log_file_name).len() as u32, o - 1);
+	}
+
+	renounce_ownership {
+		let o in 2 .. T::MaxOwners::get();
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		add_owners::<T>(&caller, b"log-file-name", o)?;
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec())
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert_eq!(AuditLogRoleStorage::<T>::get(&log_file_name).len() as u32, o - 1);
+	}
+
+	attest_audit_log {
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		let log_day = Auditor::<T>::current_day();
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), log_day)
+
+	redact_audit_log_entry {
+		let b in 0 .. T::MaxContentLen::get();
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", frame_support::sp_std::vec![0u8; b as usize])?;
+		let log_day = Auditor::<T>::current_day();
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), log_day, 0, Default::default())
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(AuditLogEntryStorage::<T>::get((&log_file_name, log_day, 0)).unwrap().is_redacted());
+	}
+
+	place_legal_hold {
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		let origin = T::LegalHoldOrigin::successful_origin();
+	}: _<T::Origin>(origin, b"log-file-name".to_vec(), Default::default())
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(AuditLogLegalHoldStorage::<T>::contains_key(&log_file_name));
+	}
+
+	delete_log_file {
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_log::<T>(&caller, b"log-file-name", Vec::new())?;
+		Auditor::<T>::open_log_for_ownership_claim(RawOrigin::Signed(caller.clone()).into(), b"log-file-name".to_vec(), [0u8; 32])?;
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec())
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(AuditLogDeletedFileStorage::<T>::contains_key(&log_file_name));
+	}
+
+	set_log_retention {
+		// Days the log file has audit logs for
+		let d in 1 .. 100;
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_logs_on_days::<T>(&caller, b"log-file-name", d)?;
+	}: _(RawOrigin::Signed(caller), b"log-file-name".to_vec(), Some(1), d)
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert_eq!(AuditLogPruneDayStorage::<T>::iter_prefix(&log_file_name).count() as u32, d);
+	}
+
+	release_legal_hold {
+		// Days the log file has audit logs for
+		let d in 1 .. 100;
+		let caller = funded::<T>(whitelisted_caller());
+		save_audit_logs_on_days::<T>(&caller, b"log-file-name", d)?;
+		Auditor::<T>::set_log_retention(RawOrigin::Signed(caller).into(), b"log-file-name".to_vec(), Some(1), d)?;
+		Auditor::<T>::place_legal_hold(T::LegalHoldOrigin::successful_origin(), b"log-file-name".to_vec(), Default::default())?;
+		let origin = T::LegalHoldOrigin::successful_origin();
+	}: _<T::Origin>(origin, b"log-file-name".to_vec(), d)
+	verify {
+		let log_file_name: AuditLogFileName<T> = b"log-file-name".to_vec().try_into().unwrap();
+		assert!(!AuditLogLegalHoldStorage::<T>::contains_key(&log_file_name));
+	}
+
+	set_feeless_reporter {
+		let reporter: T::AccountId = account("reporter", 0, SEED);
+		let origin = T::FeelessReporterOrigin::successful_origin();
+	}: _<T::Origin>(origin, reporter.clone(), true)
+	verify {
+		assert!(AuditLogFeelessReporterStorage::<T>::get(&reporter));
+	}
+
+	save_audit_logs_batch {
+		let n in 1 .. T::MaxBatchSize::get();
+		// Log files the audit logs go to, as many as there are audit logs at most
+		let f in 1 .. T::MaxBatchSize::get();
+		// Bytes of the title and content of the first audit log
+		let b in 0 .. T::MaxTitleLen::get() + T::MaxContentLen::get();
+		let caller = funded::<T>(whitelisted_caller());
+		// No more audit logs on a log file than it can take in a day
+		let max_entries_per_day = T::MaxEntriesPerDay::get();
+		let log_files = f.min(n).max((n + max_entries_per_day - 1) / max_entries_per_day);
+		// The audit logs go to the log files in turn, the title and content bytes all go to the first one
+		let audit_logs = (0..n)
+			.map(|i| {
+				let (title, content) = if i == 0 { title_and_content::<T>(b) } else { (b"log-title".to_vec(), Vec::new()) };
+				AuditLogBatchEntry::new((i % log_files).to_le_bytes().to_vec(), Some(b"2021-10-08".to_vec()), title, content, b"2021-10-08 17:30:00 UTC".to_vec(), Default::default())
+			})
+			.collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), audit_logs)
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		let first_log_file_name: AuditLogFileName<T> = 0u32.to_le_bytes().to_vec().try_into().unwrap();
+		assert_eq!(AuditLogChainHeadStorage::<T>::get(first_log_file_name).get_length(), ((n + log_files - 1) / log_files) as u64);
 	}
 }
 
-impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
\ No newline at end of file
+impl_benchmark_test_suite!(Auditor, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,50 @@
+//! Verification of the hash chain linking the audit logs of a log file.
+//!
+//! Every audit log carries its sequence in the chain of its log file and the chain hash of the
+//! audit log before it, so an exported copy of the audit logs can be checked without the chain.
+
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+
+/// Why audit logs do not form an unbroken chain.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+pub enum AuditLogChainError {
+	/// The audit log does not have the sequence following the one before it, meaning audit logs
+	/// are missing or reordered.
+	SequenceGap(u64),
+	/// The audit log does not carry the chain hash of the audit log before it, meaning an
+	/// audit log has been altered.
+	BrokenLink(u64),
+}
+
+/// Checks that the audit logs of the log file follow each other in its chain.
+///
+/// The first audit log must have the `first_sequence` and carry the `previous_hash`, which is the
+/// default hash when the chain is checked from its start. Returns the chain hash of the last
+/// audit log, to be compared with the head of the chain or the previous hash of the audit log
+/// coming after.
+pub fn verify_audit_log_chain<T: Config>(
+	log_file_name: &[u8],
+	first_sequence: u64,
+	previous_hash: T::Hash,
+	audit_logs: &[AuditLog<T>],
+) -> Result<T::Hash, AuditLogChainError> {
+	let mut expected_sequence = first_sequence;
+	let mut expected_previous_hash = previous_hash;
+
+	for audit_log in audit_logs {
+		let sequence = audit_log.clone().get_sequence();
+		if sequence != expected_sequence {
+			return Err(AuditLogChainError::SequenceGap(sequence))
+		}
+		if audit_log.clone().get_previous_hash() != expected_previous_hash {
+			return Err(AuditLogChainError::BrokenLink(sequence))
+		}
+
+		expected_sequence += 1;
+		expected_previous_hash = audit_log.chain_hash(log_file_name);
+	}
+
+	Ok(expected_previous_hash)
+}
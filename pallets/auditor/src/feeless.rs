@@ -0,0 +1,90 @@
+//! Fees refunded for the audit logs feeless reporters save within their quota.
+//!
+//! A registered reporter let by the feeless reporter origin save audit logs without fees pays the
+//! fee of an audit log like any other account when its transaction is included, and has it
+//! refunded once the audit log is saved on a log file where it has a rate limit quota, through
+//! [`Pallet::audit_log_fee`]. An audit log that fails to be saved keeps its fee. Such an audit
+//! log going over the quota, or over the daily quota of the delegation it is saved under, is
+//! rejected by [`CheckAuditLogQuota`] when the transaction is validated, before it reaches a
+//! block, only reading the storage.
+
+use crate::{Call, Config, Error, Pallet};
+use codec::{Decode, Encode};
+use frame_support::{
+	sp_runtime::{
+		traits::{DispatchInfoOf, SignedExtension},
+		transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+	},
+	sp_std::{fmt, marker::PhantomData},
+	traits::IsSubType,
+};
+use scale_info::TypeInfo;
+
+/// Custom validity error of an audit log going over the quota of its reporter.
+pub const AUDIT_LOG_QUOTA_EXCEEDED: u8 = 1;
+/// Custom validity error of an audit log going over the daily quota of the delegation of its reporter.
+pub const DELEGATE_QUOTA_EXCEEDED: u8 = 2;
+
+/// Rejects the transactions of feeless reporters saving audit logs over their quota.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckAuditLogQuota<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckAuditLogQuota<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckAuditLogQuota<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> fmt::Debug for CheckAuditLogQuota<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CheckAuditLogQuota")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckAuditLogQuota<T>
+where
+	<T as frame_system::Config>::Call: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckAuditLogQuota";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	// Checked again before dispatch, against the audit logs saved earlier in the block
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if let Some(call) = call.is_sub_type() {
+			match Pallet::<T>::check_feeless_audit_log(who, call) {
+				Some(Err(Error::<T>::RateLimitExceeded)) =>
+					return Err(InvalidTransaction::Custom(AUDIT_LOG_QUOTA_EXCEEDED).into()),
+				Some(Err(Error::<T>::DelegateQuotaExceeded)) =>
+					return Err(InvalidTransaction::Custom(DELEGATE_QUOTA_EXCEEDED).into()),
+				_ => {},
+			}
+		}
+		Ok(ValidTransaction::default())
+	}
+}
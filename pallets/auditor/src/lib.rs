@@ -1,12 +1,17 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use scale_info::prelude::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::{dispatch::{DispatchError, DispatchResult}, sp_runtime::{traits::{One, Saturating}, SaturatedConversion}, sp_std::convert::TryInto, traits::{Currency, Get, ReservableCurrency}, weights::{Pays, Weight}, BoundedVec};
 
 /// Edit this file to define custom logic or remove it if it is not needed.
 /// Learn more about FRAME and the core library of Substrate FRAME pallets:
 /// <https://substrate.dev/docs/en/knowledgebase/runtime/frame>
 pub use pallet::*;
 
+/// Number of milliseconds in a day, the unit of the on-chain timestamp being the millisecond.
+pub const MILLISECS_PER_DAY: u64 = 24 * 60 * 60 * 1000;
+
 #[cfg(test)]
 mod mock;
 
@@ -16,267 +21,2777 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod chain;
+pub mod feeless;
+pub mod merkle;
+pub mod migrations;
+pub mod weights;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
     use scale_info::TypeInfo;
     use frame_support::inherent::Vec;
+    use frame_support::traits::StorageVersion;
+    use frame_support::transactional;
+    use frame_support::traits::{Currency, ReservableCurrency};
+    use crate::weights::WeightInfo;
+    use frame_support::{BoundedVec, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound};
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + pallet_timestamp::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Number of blocks an audit log stays open for an ownership claim before it expires.
+		/// Must be greater than zero.
+		#[pallet::constant]
+		type ClaimWindow: Get<Self::BlockNumber>;
+
+		/// Maximum length of a log file name.
+		#[pallet::constant]
+		type MaxFileNameLen: Get<u32>;
+
+		/// Maximum length of the date and the timestamp claimed by the reporter of an audit log.
+		#[pallet::constant]
+		type MaxDateLen: Get<u32>;
+
+		/// Maximum length of the title of an audit log.
+		#[pallet::constant]
+		type MaxTitleLen: Get<u32>;
+
+		/// Maximum length of the content of an audit log.
+		#[pallet::constant]
+		type MaxContentLen: Get<u32>;
+
+		/// Maximum length of the locator pointing to the off-chain content of an audit log.
+		#[pallet::constant]
+		type MaxLocatorLen: Get<u32>;
+
+		/// Maximum number of accounts having a role on a log file.
+		#[pallet::constant]
+		type MaxOwners: Get<u32>;
+
+		/// Maximum number of audit logs saved on a log file for a single day.
+		#[pallet::constant]
+		type MaxEntriesPerDay: Get<u32>;
+
+		/// Maximum number of ownership claims expiring in the same block.
+		#[pallet::constant]
+		type MaxClaimsExpiringPerBlock: Get<u32>;
+
+		/// Maximum number of tags of an audit log.
+		#[pallet::constant]
+		type MaxTags: Get<u32>;
+
+		/// Maximum length of a tag of an audit log.
+		#[pallet::constant]
+		type MaxTagLen: Get<u32>;
+
+		/// Maximum number of key/value attributes of an audit log.
+		#[pallet::constant]
+		type MaxAttributes: Get<u32>;
+
+		/// Maximum length of the key of an attribute of an audit log.
+		#[pallet::constant]
+		type MaxAttributeKeyLen: Get<u32>;
+
+		/// Maximum length of the value of an attribute of an audit log.
+		#[pallet::constant]
+		type MaxAttributeValueLen: Get<u32>;
+
+		/// Maximum number of audit logs saved by a single batch.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		/// Maximum number of readers an encrypted audit log can be decrypted by.
+		#[pallet::constant]
+		type MaxReaders: Get<u32>;
+
+		/// Maximum length of the system name, environment and software version of a reporter.
+		#[pallet::constant]
+		type MaxReporterFieldLen: Get<u32>;
+
+		/// Number of blocks the bytes saved under a rate limit are counted over, such as a day of blocks.
+		#[pallet::constant]
+		type RateLimitWindow: Get<Self::BlockNumber>;
+
+		/// The currency the deposits for the audit logs kept on-chain are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Deposit reserved for every audit log saved.
+		#[pallet::constant]
+		type DepositPerItem: Get<BalanceOf<Self>>;
+
+		/// Deposit reserved for every byte of an audit log saved.
+		#[pallet::constant]
+		type DepositPerByte: Get<BalanceOf<Self>>;
+
+		/// Origin allowed to place and release legal holds on log files, freezing their audit logs.
+		type LegalHoldOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin allowed to redact the audit logs of any log file, besides the admins of the log file.
+		type PrivacyOfficerOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin allowed to let registered reporters save audit logs without fees within their quota.
+		type FeelessReporterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
+
     #[pallet::pallet]
-	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::generate_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-    #[derive(Encode, Decode, Clone, Default, Eq, PartialEq, Debug, TypeInfo)]
-    pub struct AuditLog<AccountId> {
-        // Change the timestamp to a timestamp type handled by Substrate itself
+    pub type AuditLogFileName<T> = BoundedVec<u8, <T as Config>::MaxFileNameLen>;
+    // Day an audit log was included in, counted in days since the unix epoch
+    pub type AuditLogDay = u32;
+    pub type AuditLogClaimedDate<T> = BoundedVec<u8, <T as Config>::MaxDateLen>;
+    pub type AuditLogTag<T> = BoundedVec<u8, <T as Config>::MaxTagLen>;
+    pub type AuditLogTags<T> = BoundedVec<AuditLogTag<T>, <T as Config>::MaxTags>;
+    pub type AuditLogAttributeKey<T> = BoundedVec<u8, <T as Config>::MaxAttributeKeyLen>;
+    pub type AuditLogAttributeValue<T> = BoundedVec<u8, <T as Config>::MaxAttributeValueLen>;
+    pub type AuditLogAttributes<T> = BoundedVec<(AuditLogAttributeKey<T>, AuditLogAttributeValue<T>), <T as Config>::MaxAttributes>;
+    pub type AuditLogRoles<T> = BoundedVec<(<T as frame_system::Config>::AccountId, AuditLogRole), <T as Config>::MaxOwners>;
+    // X25519 public key of an account, encrypted audit logs are decrypted with its secret key
+    pub type AuditLogEncryptionKey = [u8; 32];
+    // Content key of an encrypted audit log sealed for one of its readers, with the authentication tag
+    pub type AuditLogWrappedKey = [u8; 48];
+    pub type AuditLogNonce = [u8; 12];
+    pub type AuditLogWrappedKeys<T> = BoundedVec<(<T as frame_system::Config>::AccountId, AuditLogWrappedKey), <T as Config>::MaxReaders>;
+    pub type AuditLogEpochReaders<T> = BoundedVec<(<T as frame_system::Config>::AccountId, AuditLogEncryptionKey), <T as Config>::MaxReaders>;
+    pub type AuditLogReporterField<T> = BoundedVec<u8, <T as Config>::MaxReporterFieldLen>;
+    pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub struct AuditLog<T: Config> {
         // Reporter determines which system sent the log
-        title: Vec<u8>,
-        content: Vec<u8>,
-        timestamp: Vec<u8>,
-        reporter: AccountId,
+        title: BoundedVec<u8, T::MaxTitleLen>,
+        content: AuditLogContent<T>,
+        // Timestamp as claimed by the reporter, kept as is
+        reporter_timestamp: BoundedVec<u8, T::MaxDateLen>,
+        // On-chain moment of the block that included the log, in unix epoch milliseconds
+        timestamp: T::Moment,
+        reporter: T::AccountId,
+        // Position of the audit log in the chain of its log file, starting at zero
+        sequence: u64,
+        // Chain hash of the audit log saved before on the same log file, the default hash for the first one
+        previous_hash: T::Hash,
+        severity: Severity,
+        // Labels to find the audit log by, such as the name of a service
+        tags: AuditLogTags<T>,
+        // Key/value pairs to find the audit log by, such as a customer id
+        attributes: AuditLogAttributes<T>,
+        // Date as claimed by the reporter, kept as is
+        claimed_date: AuditLogReporterDate<T>,
     }
 
-    #[derive(Encode, Decode, Clone, Default, Eq, PartialEq, Debug, TypeInfo)]
-    pub struct AuditLogOpenForClaim<AccountId> {
-        filename: Vec<u8>,
-        assigned_claimer: AccountId,
-        // timestamp is in unix epoch
-        opened_for_claim_timestamp: u64,
+    // Date claimed by the reporter of an audit log, encoded as an `Option` of the date when it is not a legacy one
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub enum AuditLogReporterDate<T: Config> {
+        Unclaimed,
+        // Date given along with the audit log, committed to by its chain hash
+        Claimed(AuditLogClaimedDate<T>),
+        // Date an audit log saved before the audit logs were kept under days was stored under. It was never part of the
+        // chain hash of the audit log, which still does not commit to it so that the chain saved back then stays valid
+        Legacy(AuditLogClaimedDate<T>),
     }
 
-    impl <T> AuditLog<T> {
-        pub fn get_title(self) -> Vec<u8> {
-            self.title
+    // Severity of an audit log, from the least to the most severe
+    #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Debug, TypeInfo, MaxEncodedLen)]
+    pub enum Severity {
+        Debug,
+        Info,
+        Notice,
+        Warning,
+        Error,
+        Critical,
+        Alert,
+        Emergency,
+    }
+
+    impl Default for Severity {
+        fn default() -> Self {
+            Severity::Info
         }
+    }
 
-        pub fn get_content(self) -> Vec<u8> {
-            self.content
+    // Number of severities, bounding the severities allowed by a log schema
+    pub struct SeverityCount;
+
+    impl Get<u32> for SeverityCount {
+        fn get() -> u32 {
+            8
         }
+    }
 
-        pub fn get_timestamp(self) -> Vec<u8> {
-            self.timestamp
+    // Kind of content the audit logs of a log file may have
+    #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub enum AuditLogContentType {
+        // Content kept on-chain or off-chain
+        Any,
+        // Content kept on-chain
+        OnChain,
+        // Content kept on-chain that is valid UTF-8 text
+        Utf8,
+        // Content kept off-chain, only its digest is on-chain
+        OffChain,
+        // Content kept on-chain encrypted for its readers
+        Encrypted,
+    }
+
+    // Rules the audit logs saved on a log file must follow
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub struct AuditLogSchema<T: Config> {
+        // Keys of the attributes every audit log must have
+        required_attribute_keys: BoundedVec<AuditLogAttributeKey<T>, T::MaxAttributes>,
+        // Severities an audit log may have, any severity when empty
+        allowed_severities: BoundedVec<Severity, SeverityCount>,
+        // Maximum length of the content, whether kept on-chain or not
+        max_content_length: Option<u32>,
+        content_type: AuditLogContentType,
+    }
+
+    impl <T: Config> AuditLogSchema<T> {
+        pub fn new(required_attribute_keys: BoundedVec<AuditLogAttributeKey<T>, T::MaxAttributes>, allowed_severities: BoundedVec<Severity, SeverityCount>, max_content_length: Option<u32>, content_type: AuditLogContentType) -> Self {
+            AuditLogSchema { required_attribute_keys, allowed_severities, max_content_length, content_type }
         }
 
-        pub fn get_reporter(self) -> T {
-            self.reporter
+        /// Checks that the audit log follows the schema.
+        pub fn check(&self, audit_log: &AuditLog<T>) -> Result<(), Error<T>> {
+            for key in self.required_attribute_keys.iter() {
+                frame_support::ensure!(audit_log.attributes.iter().any(|(own_key, _)| own_key == key), Error::<T>::AuditLogMissingRequiredAttribute);
+            }
+            frame_support::ensure!(
+                self.allowed_severities.is_empty() || self.allowed_severities.contains(&audit_log.severity),
+                Error::<T>::AuditLogSeverityNotAllowedBySchema
+            );
+
+            let (_, content_length) = audit_log.content.digest();
+            frame_support::ensure!(self.max_content_length.map_or(true, |max| content_length <= max), Error::<T>::AuditLogContentTooLongForSchema);
+
+            let content_type_allowed = match (&self.content_type, &audit_log.content) {
+                (AuditLogContentType::Any, _) => true,
+                (AuditLogContentType::OnChain, AuditLogContent::Plain(_)) => true,
+                (AuditLogContentType::Utf8, AuditLogContent::Plain(content)) => core::str::from_utf8(content).is_ok(),
+                (AuditLogContentType::OffChain, AuditLogContent::Hashed { .. }) => true,
+                (AuditLogContentType::Encrypted, AuditLogContent::Encrypted(_)) => true,
+                _ => false,
+            };
+            frame_support::ensure!(content_type_allowed, Error::<T>::AuditLogContentTypeNotAllowedBySchema);
+
+            Ok(())
         }
     }
 
-    impl <T> AuditLogOpenForClaim<T> {
-        pub fn get_filename(self) -> Vec<u8> {
-            self.filename
+    // Severity, tags and attributes of an audit log to save, bounded once saved
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct AuditLogMetadata {
+        severity: Severity,
+        tags: Vec<Vec<u8>>,
+        attributes: Vec<(Vec<u8>, Vec<u8>)>,
+    }
+
+    impl AuditLogMetadata {
+        pub fn new(severity: Severity, tags: Vec<Vec<u8>>, attributes: Vec<(Vec<u8>, Vec<u8>)>) -> Self {
+            AuditLogMetadata { severity, tags, attributes }
         }
+    }
 
-        pub fn get_assigned_claimer(self) -> T {
-            self.assigned_claimer
+    // Content of an audit log, either kept on-chain or only committed to by its digest
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub enum AuditLogContent<T: Config> {
+        // The content itself
+        Plain(BoundedVec<u8, T::MaxContentLen>),
+        // The content is kept off-chain, digest is computed with the hashing of the runtime
+        Hashed {
+            digest: T::Hash,
+            length: u32,
+            // Where the content can be fetched from, such as an URL or a storage object key
+            locator: Option<BoundedVec<u8, T::MaxLocatorLen>>,
+        },
+        // The content has been erased, only its digest is left to prove what it was
+        Redacted {
+            digest: T::Hash,
+            length: u32,
+            // Admin who redacted the content, none when redacted by the privacy officer origin
+            redacted_by: Option<T::AccountId>,
+            // On-chain moment of the block that redacted the content, in unix epoch milliseconds
+            redacted_at: T::Moment,
+            // Hash of the reason the content has been redacted
+            reason_hash: T::Hash,
+        },
+        // The content is kept on-chain encrypted, only its readers can decrypt it
+        Encrypted(AuditLogEncryptedContent<T>),
+    }
+
+    // Content encrypted with a content key of its own, the content key being sealed for every reader.
+    //
+    // The content is encrypted with ChaCha20-Poly1305 under the content key and the nonce. For every reader, the
+    // content key is encrypted with ChaCha20-Poly1305 under the blake2-256 hash of the "auditor-key-wrap" context,
+    // the x25519 shared secret of the ephemeral key and the encryption key of the reader, the ephemeral public key
+    // and the encryption key of the reader, with a zero nonce.
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub struct AuditLogEncryptedContent<T: Config> {
+        ciphertext: BoundedVec<u8, T::MaxContentLen>,
+        nonce: AuditLogNonce,
+        // Public key of the x25519 key pair generated to seal the content key for the readers
+        ephemeral_public_key: AuditLogEncryptionKey,
+        wrapped_keys: AuditLogWrappedKeys<T>,
+        // Key epoch of the log file the content key is sealed in, zero when the log file had none
+        key_epoch: u32,
+    }
+
+    impl <T: Config> AuditLogEncryptedContent<T> {
+        pub fn new(ciphertext: BoundedVec<u8, T::MaxContentLen>, nonce: AuditLogNonce, ephemeral_public_key: AuditLogEncryptionKey, wrapped_keys: AuditLogWrappedKeys<T>, key_epoch: u32) -> Self {
+            AuditLogEncryptedContent { ciphertext, nonce, ephemeral_public_key, wrapped_keys, key_epoch }
         }
 
-        pub fn get_opened_for_claim_timestamp(self) -> u64 {
-            self.opened_for_claim_timestamp
+        pub fn get_key_epoch(self) -> u32 {
+            self.key_epoch
+        }
+
+        pub fn get_ciphertext(self) -> Vec<u8> {
+            self.ciphertext.into_inner()
+        }
+
+        pub fn get_nonce(self) -> AuditLogNonce {
+            self.nonce
+        }
+
+        pub fn get_ephemeral_public_key(self) -> AuditLogEncryptionKey {
+            self.ephemeral_public_key
+        }
+
+        /// Content key sealed for the reader, if the content can be decrypted by the reader.
+        pub fn wrapped_key_of(&self, reader: &T::AccountId) -> Option<AuditLogWrappedKey> {
+            self.wrapped_keys.iter().find(|(own_reader, _)| own_reader == reader).map(|(_, wrapped_key)| *wrapped_key)
         }
     }
 
-    pub type AuditLogFileName = Vec<u8>;
-    pub type AuditLogDate = Vec<u8>;
-    //pub type AuditLogCollection<T> = Vec<AuditLog<T>>; // not used
-    //pub type AuditLogOwnerCollection<T> = Vec<T::AccountId>; // not used
-    //pub type AuditLogClaimCode<T> = T::AccountId;
+    #[derive(Encode, Decode, CloneNoBound, DefaultNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub struct AuditLogOpenForClaim<T: Config> {
+        filename: AuditLogFileName<T>,
+        assigned_claimer: T::AccountId,
+        // timestamp is in unix epoch milliseconds
+        opened_for_claim_timestamp: T::Moment,
+        // block number from which the claim can no longer be made
+        expires_at: T::BlockNumber,
+    }
 
+    // Role of an account on a log file. Admins are the owners of the log file.
+    #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub enum AuditLogRole {
+        // Manages the members and the ownership claims of the log file, and can write and attest
+        Admin,
+        // Saves audit logs on the log file
+        Writer,
+        // Only listed as a reader of the log file
+        Reader,
+        // Attests the audit logs of the log file
+        Auditor,
+    }
 
-    #[pallet::storage]
-    #[pallet::getter(fn retrieve_audit_log)]
-    pub(super) type AuditLogStorage<T: Config> = StorageDoubleMap<_, Blake2_128Concat, AuditLogFileName, Blake2_128Concat, AuditLogDate, Vec<AuditLog<T::AccountId>>, ValueQuery>;
+    impl AuditLogRole {
+        pub fn can_manage(&self) -> bool {
+            matches!(self, AuditLogRole::Admin)
+        }
 
-    #[pallet::storage]
-    #[pallet::getter(fn retrieve_audit_log_owner)]
-    pub(super) type AuditLogOwnerStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName, Vec<T::AccountId>, ValueQuery>;
+        pub fn can_write(&self) -> bool {
+            matches!(self, AuditLogRole::Admin | AuditLogRole::Writer)
+        }
 
-    #[pallet::storage]
-    #[pallet::getter(fn retrieve_audit_log_open_for_claim)]
-    pub(super) type AuditLogOpenForClaimStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName, AuditLogOpenForClaim<T::AccountId>, ValueQuery>;
-   
-    #[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// Event documentation should end with an array that provides descriptive details for event
-		/// parameters. [something, who]
-		AuditLogInformationStored(AuditLogFileName, AuditLogDate, T::AccountId),
-        // T::AccountId is included to specify who claimed the open log
-        AuditLogClaimedForOwnership(AuditLogFileName, T::AccountId),
-        // T::AccountId is included to specify who opened the log for claiming
-        AuditLogOpenedForOwnershipClaim(AuditLogFileName, T::AccountId),
-	}
+        pub fn can_attest(&self) -> bool {
+            matches!(self, AuditLogRole::Admin | AuditLogRole::Auditor)
+        }
+    }
 
-    // Errors inform users that something went wrong.
-	#[pallet::error]
-	pub enum Error<T> {
-        AuditLogIdentifierCannotBeUsed,
-        NoRightsToOpenAuditLogForClaiming,
-        NotExistingAuditLogCantBeOpenForClaiming,
-        NotAuthorizedToClaimAuditLog,
-        AuditLogAlreadyOpenedForClaiming,
-        AuditLogCantBeFound,
-        AuditLogNotOpenedForClaiming
-	}
+    // Latest link of the hash chain of a log file
+    #[derive(Encode, Decode, CloneNoBound, DefaultNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub struct AuditLogChainHead<T: Config> {
+        // Chain hash of the latest audit log of the log file
+        hash: T::Hash,
+        // Number of audit logs in the chain, which is also the sequence of the next one
+        length: u64,
+    }
 
-    // Dispatchable functions allows users to interact with the pallet and invoke state changes.
-	// These functions materialize as "extrinsics", which are often compared to transactions.
-	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// An example dispatchable that takes a singles value as a parameter, writes the value to
-		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
-		/// To add audit log
-        #[pallet::weight(0)]
-        pub fn save_audit_log(origin: OriginFor<T>, log_file_name: Vec<u8>, log_date: Vec<u8>, log_title: Vec<u8>, log_content: Vec<u8>, log_timestamp: Vec<u8>) -> DispatchResult {
+    impl <T: Config> AuditLogChainHead<T> {
+        pub fn new(hash: T::Hash, length: u64) -> Self {
+            AuditLogChainHead { hash, length }
+        }
 
-            // The dispatch origin of this call must be a participant.
-            let sender = ensure_signed(origin)?;
+        pub fn get_hash(self) -> T::Hash {
+            self.hash
+        }
 
-            let audit_log = AuditLog {
-                title: log_title,
-                content: log_content,
-                timestamp: log_timestamp,
-                reporter: sender.clone(),
-            };
+        pub fn get_length(self) -> u64 {
+            self.length
+        }
+    }
 
-            let log_owners = AuditLogOwnerStorage::<T>::try_get(&log_file_name);
-            match log_owners {
-                // log file name is already owned, meaning it is existing
-                Ok(owners) => {
-                    // check if log file name owner is the transaction sender
-                    if owners.contains(&sender) {
-                        let mut audit_log_collection = <AuditLogStorage<T>>::get(&log_file_name, &log_date);
-                        audit_log_collection.push(audit_log.clone());
-                        <AuditLogStorage<T>>::insert(&log_file_name, &log_date, audit_log_collection);
-                    } else {
-                        frame_support::ensure!(0 == 1, <Error<T>>::AuditLogIdentifierCannotBeUsed);
-                    }
-                }
-                Err(_error) => {
-                    // No owner for this log name yet, therefore it is not existing and is available
-                    let mut new_audit_log_collection = Vec::new();
-                    new_audit_log_collection.push(audit_log.clone());
-                    <AuditLogStorage<T>>::insert(&log_file_name, &log_date, new_audit_log_collection);
-
-                    // Track that the log name is owned by the sender
-                    let mut new_audit_log_owners_collection = Vec::new();
-                    new_audit_log_owners_collection.push(sender.clone());
-                    <AuditLogOwnerStorage<T>>::insert(&log_file_name, new_audit_log_owners_collection);
-                }
-            }
+    // Audit log to save as part of a batch, on any log file
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct AuditLogBatchEntry {
+        log_file_name: Vec<u8>,
+        log_claimed_date: Option<Vec<u8>>,
+        log_title: Vec<u8>,
+        log_content: Vec<u8>,
+        log_reporter_timestamp: Vec<u8>,
+        log_metadata: AuditLogMetadata,
+    }
 
-            // Emit the event that audit log has been added in chain
-            Self::deposit_event(Event::AuditLogInformationStored(log_file_name, log_date, sender));
+    impl AuditLogBatchEntry {
+        pub fn new(log_file_name: Vec<u8>, log_claimed_date: Option<Vec<u8>>, log_title: Vec<u8>, log_content: Vec<u8>, log_reporter_timestamp: Vec<u8>, log_metadata: AuditLogMetadata) -> Self {
+            AuditLogBatchEntry { log_file_name, log_claimed_date, log_title, log_content, log_reporter_timestamp, log_metadata }
+        }
 
-            // Return a successful DispatchResult
-            Ok(())
+        /// Number of distinct log files the audit logs of a batch go to.
+        pub fn log_file_count(audit_logs: &[AuditLogBatchEntry]) -> u32 {
+            audit_logs.iter().map(|audit_log| &audit_log.log_file_name).collect::<frame_support::sp_std::collections::btree_set::BTreeSet<_>>().len() as u32
         }
+    }
 
-        #[pallet::weight(0)]
-        pub fn claim_log(origin: OriginFor<T>, log_file_name: Vec<u8>) -> DispatchResult {
+    // Merkle tree over the audit logs of a log file for a day, see the merkle module
+    #[derive(Encode, Decode, CloneNoBound, DefaultNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub struct AuditLogMerkleTree<T: Config> {
+        root: T::Hash,
+        // Rightmost branch of the tree, needed to add the next audit log
+        branch: [T::Hash; crate::merkle::AUDIT_LOG_MERKLE_DEPTH],
+    }
 
-            // The dispatch origin of this call must be a participant.
-            let claimer = ensure_signed(origin)?;
+    // Readers the content keys of the encrypted audit logs saved on a log file are sealed for, along with the
+    // encryption keys they are sealed with, until the next key epoch starts
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub struct AuditLogKeyEpoch<T: Config> {
+        // Starting at one, zero being left for the encrypted audit logs saved before the first key epoch
+        epoch: u32,
+        readers: AuditLogEpochReaders<T>,
+    }
 
-            
+    impl <T: Config> AuditLogKeyEpoch<T> {
+        pub fn new(epoch: u32, readers: AuditLogEpochReaders<T>) -> Self {
+            AuditLogKeyEpoch { epoch, readers }
+        }
 
-            // Checks if the audit log is open for claiming, and returns the assigned claimer for the log available for claiming
-            let audit_log_open_for_claim = AuditLogOpenForClaimStorage::<T>::try_get(&log_file_name);
-            match audit_log_open_for_claim {
-                Ok(audit_log_for_claim) => {
+        pub fn get_epoch(self) -> u32 {
+            self.epoch
+        }
 
-                    // TODO: Check if the log file name claiming has expired
+        pub fn get_readers(self) -> AuditLogEpochReaders<T> {
+            self.readers
+        }
 
-                    // Checks if claimer is the assigned claimer
-                    frame_support::ensure!(&claimer == &audit_log_for_claim.assigned_claimer, <Error<T>>::NotAuthorizedToClaimAuditLog);
+        /// Whether the content key is sealed for exactly the readers of the key epoch.
+        pub fn is_sealed_for_readers(&self, wrapped_keys: &AuditLogWrappedKeys<T>) -> bool {
+            wrapped_keys.len() == self.readers.len()
+                && self.readers.iter().all(|(reader, _)| wrapped_keys.iter().any(|(sealed_for, _)| sealed_for == reader))
+        }
+    }
 
-                    Self::add_claimer_as_log_owner(&log_file_name, claimer.clone());
+    // System an account saves audit logs for, as registered by the account
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub struct AuditLogReporter<T: Config> {
+        account: T::AccountId,
+        // Such as payments-api
+        system_name: AuditLogReporterField<T>,
+        // Such as prod or staging
+        environment: AuditLogReporterField<T>,
+        software_version: AuditLogReporterField<T>,
+        // Hash of how to reach the team running the system, kept off-chain
+        contact_hash: T::Hash,
+        // Retired reporters stay registered for the audit logs they saved before
+        active: bool,
+    }
 
-                    // Emit the event that the audit log has been claimed
-                    Self::deposit_event(Event::AuditLogClaimedForOwnership(log_file_name, claimer));
-                }
-                Err(_error) => {
-                    // Raise error when the log file is not opened for claiming
-                    frame_support::ensure!(0 == 1, <Error<T>>::AuditLogNotOpenedForClaiming);
-                }
-            }
+    impl <T: Config> AuditLogReporter<T> {
+        pub fn new(account: T::AccountId, system_name: AuditLogReporterField<T>, environment: AuditLogReporterField<T>, software_version: AuditLogReporterField<T>, contact_hash: T::Hash) -> Self {
+            AuditLogReporter { account, system_name, environment, software_version, contact_hash, active: true }
+        }
 
-            // Return a successful DispatchResult
+        pub fn retire(&mut self) {
+            self.active = false;
+        }
+
+        /// How query tools show the reporter, its system name followed by its environment, such as `payments-api (prod)`.
+        pub fn label(&self) -> Vec<u8> {
+            [&self.system_name[..], b" (", &self.environment[..], b")"].concat()
+        }
+
+        pub fn get_account(self) -> T::AccountId {
+            self.account
+        }
+
+        pub fn get_system_name(self) -> Vec<u8> {
+            self.system_name.into_inner()
+        }
+
+        pub fn get_environment(self) -> Vec<u8> {
+            self.environment.into_inner()
+        }
+
+        pub fn get_software_version(self) -> Vec<u8> {
+            self.software_version.into_inner()
+        }
+
+        pub fn get_contact_hash(self) -> T::Hash {
+            self.contact_hash
+        }
+
+        pub fn is_active(&self) -> bool {
+            self.active
+        }
+    }
+
+    // Authorization of an account to save audit logs on a log file on behalf of its admins
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub struct AuditLogDelegation<T: Config> {
+        // block number from which the delegate can no longer save audit logs, never when there is none
+        expires_at: Option<T::BlockNumber>,
+        // Maximum number of audit logs the delegate can save per day, unlimited when there is none
+        daily_quota: Option<u32>,
+        // Day the audit logs saved by the delegate are counted for
+        quota_day: AuditLogDay,
+        entries_on_quota_day: u32,
+    }
+
+    impl <T: Config> AuditLogDelegation<T> {
+        pub fn new(expires_at: Option<T::BlockNumber>, daily_quota: Option<u32>) -> Self {
+            AuditLogDelegation { expires_at, daily_quota, quota_day: 0, entries_on_quota_day: 0 }
+        }
+
+        /// Checks that the delegation still allows the delegate to save an audit log on the day at the block.
+        pub fn check_entry(&self, now: T::BlockNumber, day: AuditLogDay) -> Result<(), Error<T>> {
+            frame_support::ensure!(self.expires_at.map_or(true, |expires_at| now < expires_at), Error::<T>::DelegationExpired);
+            let entries_on_day = if self.quota_day == day { self.entries_on_quota_day } else { 0 };
+            frame_support::ensure!(self.daily_quota.map_or(true, |daily_quota| entries_on_day < daily_quota), Error::<T>::DelegateQuotaExceeded);
             Ok(())
         }
 
-        // TODO: Clean up the code for this function if possible 
-        #[pallet::weight(0)]
-        pub fn open_log_for_ownership_claim(origin: OriginFor<T>, log_file_name: Vec<u8>, claimer_pubkey: [u8; 32]) -> DispatchResult {
+        /// Counts an audit log saved by the delegate on the day, if the delegation still allows it at the block.
+        pub fn record_entry(&mut self, now: T::BlockNumber, day: AuditLogDay) -> Result<(), Error<T>> {
+            self.check_entry(now, day)?;
+            if self.quota_day != day {
+                self.quota_day = day;
+                self.entries_on_quota_day = 0;
+            }
+            self.entries_on_quota_day += 1;
+            Ok(())
+        }
 
-            // The dispatch origin of this call must be a participant.
-            let sender = ensure_signed(origin)?;
+        pub fn get_expires_at(self) -> Option<T::BlockNumber> {
+            self.expires_at
+        }
 
-            // Check if log is owned by someone
-            let log_owners = AuditLogOwnerStorage::<T>::try_get(&log_file_name);
-            match log_owners {
-                // Log is owned by someone
-                Ok(owners) => {
-                    // Check if log is owned by the function caller
-                    if owners.contains(&sender) {
-                        // Check if the file is already open for claiming. Only one claiming can happen at a time
-                        let is_log_already_open = AuditLogOpenForClaimStorage::<T>::try_get(&log_file_name);
-                        match is_log_already_open {
-                            Ok(_is_log_already_open) => {
-                               // Raise an error that the log is already opened
-                                frame_support::ensure!(0 == 1, <Error<T>>::AuditLogAlreadyOpenedForClaiming);
-                            }
-                            Err(_error) => {
-                                // Convert u32 raw byte to AccountId of the would be assigned claimer
-                                let claimer_account_id = T::AccountId::decode(&mut &claimer_pubkey[..]).unwrap_or_default();
+        pub fn get_daily_quota(self) -> Option<u32> {
+            self.daily_quota
+        }
+    }
 
-                                let audit_log_open_for_claim = AuditLogOpenForClaim {
-                                    filename: log_file_name.clone(),
-                                    assigned_claimer: claimer_account_id.clone(),
-                                    opened_for_claim_timestamp: 123456789, // dummy epoch date
-                                };
+    // Maximum audit logs saved on a log file, or by a reporter on it, each limit being unlimited when there is none
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct AuditLogRateLimit {
+        max_entries_per_block: Option<u32>,
+        // Bytes of the encoded audit logs, counted over windows of RateLimitWindow blocks
+        max_bytes_per_window: Option<u32>,
+    }
 
-                                // Open the log for ownership claim 
-                                <AuditLogOpenForClaimStorage<T>>::insert(&log_file_name, audit_log_open_for_claim);
+    impl AuditLogRateLimit {
+        pub fn new(max_entries_per_block: Option<u32>, max_bytes_per_window: Option<u32>) -> Self {
+            AuditLogRateLimit { max_entries_per_block, max_bytes_per_window }
+        }
 
-                                // Emit the event that an audit log has been opened for claiming
-                                Self::deposit_event(Event::AuditLogOpenedForOwnershipClaim(log_file_name, sender));
-                            }
-                        }
-                    } else {
-                        frame_support::ensure!(0 == 1, <Error<T>>::NoRightsToOpenAuditLogForClaiming);
-                    }
-                }
-                Err(_error) => {
-                    // The log opened does not exist since it has no owner, Raise an error
-                    frame_support::ensure!(0 == 1, <Error<T>>::AuditLogCantBeFound);
+        /// Whether the rate limit limits the audit logs per block or the bytes per window, or neither of them.
+        pub fn is_limited(&self) -> bool {
+            self.max_entries_per_block.is_some() || self.max_bytes_per_window.is_some()
+        }
+
+        /// Audit logs left for the block and bytes left for the window starting at `window_start`, none meaning unlimited.
+        pub fn remaining<T: Config>(&self, usage: &AuditLogRateUsage<T>, now: T::BlockNumber, window_start: T::BlockNumber) -> (Option<u32>, Option<u32>) {
+            let entries_in_block = if usage.block == now { usage.entries_in_block } else { 0 };
+            let bytes_in_window = if usage.window_start == window_start { usage.bytes_in_window } else { 0 };
+            (
+                self.max_entries_per_block.map(|max_entries| max_entries.saturating_sub(entries_in_block)),
+                self.max_bytes_per_window.map(|max_bytes| max_bytes.saturating_sub(bytes_in_window)),
+            )
+        }
+
+        /// Counts an audit log of the given size saved at the block, if the rate limit allows it.
+        pub fn record<T: Config>(&self, usage: &mut AuditLogRateUsage<T>, now: T::BlockNumber, window_start: T::BlockNumber, bytes: u32) -> Result<(), Error<T>> {
+            let (entries_left, bytes_left) = self.remaining(usage, now, window_start);
+            frame_support::ensure!(
+                entries_left.map_or(true, |entries_left| entries_left > 0) && bytes_left.map_or(true, |bytes_left| bytes <= bytes_left),
+                Error::<T>::RateLimitExceeded
+            );
+            usage.record(now, window_start, bytes);
+            Ok(())
+        }
+    }
+
+    // Audit logs saved under a rate limit, counted for the latest block and window they were saved in
+    #[derive(Encode, Decode, CloneNoBound, DefaultNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound(T: Config))]
+    pub struct AuditLogRateUsage<T: Config> {
+        block: T::BlockNumber,
+        entries_in_block: u32,
+        // First block of the window
+        window_start: T::BlockNumber,
+        bytes_in_window: u32,
+    }
+
+    impl <T: Config> AuditLogRateUsage<T> {
+        /// Counts an audit log of the given size, the counts of the blocks and windows before being reset.
+        pub fn record(&mut self, now: T::BlockNumber, window_start: T::BlockNumber, bytes: u32) {
+            if self.block != now {
+                self.block = now;
+                self.entries_in_block = 0;
+            }
+            if self.window_start != window_start {
+                self.window_start = window_start;
+                self.bytes_in_window = 0;
+            }
+            self.entries_in_block += 1;
+            self.bytes_in_window = self.bytes_in_window.saturating_add(bytes);
+        }
+    }
+
+    impl <T: Config> AuditLogMerkleTree<T> {
+        /// Adds the chain hash of the audit log at the index within the day as a leaf.
+        pub fn insert(&mut self, leaf_index: u32, leaf: T::Hash) {
+            crate::merkle::merkle_insert::<T::Hashing>(&mut self.branch, leaf_index, leaf);
+            self.root = crate::merkle::merkle_root::<T::Hashing>(&self.branch, leaf_index + 1);
+        }
+
+        pub fn get_root(self) -> T::Hash {
+            self.root
+        }
+    }
+
+    impl <T: Config> AuditLogContent<T> {
+        /// Whether the presented payload is the content of the audit log.
+        pub fn matches(&self, payload: &[u8]) -> bool {
+            match self {
+                AuditLogContent::Plain(content) => &content[..] == payload,
+                AuditLogContent::Encrypted(encrypted) => &encrypted.ciphertext[..] == payload,
+                AuditLogContent::Hashed { digest, length, .. } | AuditLogContent::Redacted { digest, length, .. } => {
+                    *length as usize == payload.len() && *digest == <T::Hashing as frame_support::sp_runtime::traits::Hash>::hash(payload)
                 }
             }
-            
-            // Return a successful DispatchResult
-            Ok(())
         }
-	}
-}
 
-impl<T: Config> Pallet<T> {
+        /// Digest and length of the content, the same whether the content is kept on-chain or not.
+        pub fn digest(&self) -> (T::Hash, u32) {
+            match self {
+                AuditLogContent::Plain(content) => (<T::Hashing as frame_support::sp_runtime::traits::Hash>::hash(&content[..]), content.len() as u32),
+                AuditLogContent::Encrypted(encrypted) => {
+                    (<T::Hashing as frame_support::sp_runtime::traits::Hash>::hash(&encrypted.ciphertext[..]), encrypted.ciphertext.len() as u32)
+                },
+                AuditLogContent::Hashed { digest, length, .. } | AuditLogContent::Redacted { digest, length, .. } => (*digest, *length),
+            }
+        }
+    }
 
-	fn add_claimer_as_log_owner(log_file_name: &Vec<u8>, claimer: T::AccountId) {
-		// Add the claimer as an owner of the audit log
-        let mut audit_log_owners_collection = AuditLogOwnerStorage::<T>::get(log_file_name);
-        audit_log_owners_collection.push(claimer);
-        AuditLogOwnerStorage::<T>::insert(log_file_name, audit_log_owners_collection);
+    impl <T: Config> AuditLog<T> {
+        pub fn new(title: BoundedVec<u8, T::MaxTitleLen>, content: AuditLogContent<T>, reporter_timestamp: BoundedVec<u8, T::MaxDateLen>, timestamp: T::Moment, reporter: T::AccountId, sequence: u64, previous_hash: T::Hash) -> Self {
+            AuditLog {
+                title,
+                content,
+                reporter_timestamp,
+                timestamp,
+                reporter,
+                sequence,
+                previous_hash,
+                severity: Default::default(),
+                tags: Default::default(),
+                attributes: Default::default(),
+                claimed_date: AuditLogReporterDate::Unclaimed,
+            }
+        }
 
-        // Remove the audit log as open for claiming so that is not open anymore (delete from the AuditLogOpenForClaimStorage)
-        AuditLogOpenForClaimStorage::<T>::remove(log_file_name);
-	}
-}
\ No newline at end of file
+        /// Sets the date claimed by the reporter of the audit log.
+        pub fn with_claimed_date(mut self, claimed_date: Option<AuditLogClaimedDate<T>>) -> Self {
+            self.claimed_date = claimed_date.map_or(AuditLogReporterDate::Unclaimed, AuditLogReporterDate::Claimed);
+            self
+        }
+
+        /// Sets the severity, tags and attributes of the audit log.
+        pub fn with_metadata(mut self, severity: Severity, tags: AuditLogTags<T>, attributes: AuditLogAttributes<T>) -> Self {
+            self.severity = severity;
+            self.tags = tags;
+            self.attributes = attributes;
+            self
+        }
+
+        /// Replaces the content with its digest, which keeps the chain hash of the audit log and so the chain and
+        /// Merkle tree of its log file valid.
+        pub fn redact(&mut self, redacted_by: Option<T::AccountId>, redacted_at: T::Moment, reason_hash: T::Hash) {
+            let (digest, length) = self.content.digest();
+            self.content = AuditLogContent::Redacted { digest, length, redacted_by, redacted_at, reason_hash };
+        }
+
+        pub fn is_redacted(&self) -> bool {
+            matches!(self.content, AuditLogContent::Redacted { .. })
+        }
+
+        /// Whether the audit log has the given tag.
+        pub fn has_tag(&self, tag: &[u8]) -> bool {
+            self.tags.iter().any(|own_tag| &own_tag[..] == tag)
+        }
+
+        /// Whether the audit log has the attribute with the given key and value.
+        pub fn has_attribute(&self, key: &[u8], value: &[u8]) -> bool {
+            self.attributes.iter().any(|(own_key, own_value)| &own_key[..] == key && &own_value[..] == value)
+        }
+
+        /// Places the audit log at the sequence of the chain of its log file, right after the audit log with the previous hash.
+        pub fn link(&mut self, sequence: u64, previous_hash: T::Hash) {
+            self.sequence = sequence;
+            self.previous_hash = previous_hash;
+        }
+
+        /// Hash committing to the audit log and, through its previous hash, to every audit log saved before it on the log file.
+        ///
+        /// The content is committed to by its digest, so an audit log keeps its hash whether its content is on-chain or not.
+        /// Audit logs with the default severity and without tags, attributes or claimed date hash as they did before having them,
+        /// legacy claimed dates not being committed to.
+        pub fn chain_hash(&self, log_file_name: &[u8]) -> T::Hash {
+            let (content_digest, content_length) = self.content.digest();
+            let commitment = (
+                log_file_name,
+                self.sequence,
+                &self.previous_hash,
+                &self.title,
+                content_digest,
+                content_length,
+                &self.reporter_timestamp,
+                &self.timestamp,
+                &self.reporter,
+            );
+            let mut encoded = commitment.encode();
+            let has_metadata = self.severity != Severity::default() || !self.tags.is_empty() || !self.attributes.is_empty();
+            let claimed_date = match &self.claimed_date {
+                AuditLogReporterDate::Claimed(claimed_date) => Some(claimed_date),
+                AuditLogReporterDate::Unclaimed | AuditLogReporterDate::Legacy(_) => None,
+            };
+            if has_metadata || claimed_date.is_some() {
+                (&self.severity, &self.tags, &self.attributes).encode_to(&mut encoded);
+            }
+            if let Some(claimed_date) = claimed_date {
+                claimed_date.encode_to(&mut encoded);
+            }
+            <T::Hashing as frame_support::sp_runtime::traits::Hash>::hash(&encoded)
+        }
+
+        pub fn get_title(self) -> Vec<u8> {
+            self.title.into_inner()
+        }
+
+        pub fn get_content(self) -> AuditLogContent<T> {
+            self.content
+        }
+
+        pub fn get_reporter_timestamp(self) -> Vec<u8> {
+            self.reporter_timestamp.into_inner()
+        }
+
+        pub fn get_timestamp(self) -> T::Moment {
+            self.timestamp
+        }
+
+        pub fn get_reporter(self) -> T::AccountId {
+            self.reporter
+        }
+
+        pub fn get_sequence(self) -> u64 {
+            self.sequence
+        }
+
+        pub fn get_previous_hash(self) -> T::Hash {
+            self.previous_hash
+        }
+
+        pub fn get_severity(self) -> Severity {
+            self.severity
+        }
+
+        pub fn get_tags(self) -> AuditLogTags<T> {
+            self.tags
+        }
+
+        pub fn get_attributes(self) -> AuditLogAttributes<T> {
+            self.attributes
+        }
+
+        /// Returns the date claimed by the reporter, a legacy one included.
+        pub fn get_claimed_date(self) -> Option<Vec<u8>> {
+            match self.claimed_date {
+                AuditLogReporterDate::Unclaimed => None,
+                AuditLogReporterDate::Claimed(claimed_date) | AuditLogReporterDate::Legacy(claimed_date) => Some(claimed_date.into_inner()),
+            }
+        }
+
+        pub fn get_reporter_date(self) -> AuditLogReporterDate<T> {
+            self.claimed_date
+        }
+    }
+
+    impl <T: Config> AuditLogOpenForClaim<T> {
+        pub fn new(filename: AuditLogFileName<T>, assigned_claimer: T::AccountId, opened_for_claim_timestamp: T::Moment, expires_at: T::BlockNumber) -> Self {
+            AuditLogOpenForClaim { filename, assigned_claimer, opened_for_claim_timestamp, expires_at }
+        }
+
+        pub fn get_filename(self) -> Vec<u8> {
+            self.filename.into_inner()
+        }
+
+        pub fn get_assigned_claimer(self) -> T::AccountId {
+            self.assigned_claimer
+        }
+
+        pub fn get_opened_for_claim_timestamp(self) -> T::Moment {
+            self.opened_for_claim_timestamp
+        }
+
+        pub fn get_expires_at(self) -> T::BlockNumber {
+            self.expires_at
+        }
+    }
+
+    // Every audit log is kept on its own, keyed by its log file name, day and index within the day
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_audit_log_entry)]
+    pub(super) type AuditLogEntryStorage<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, AuditLogFileName<T>>,
+            NMapKey<Twox64Concat, AuditLogDay>,
+            NMapKey<Twox64Concat, u32>,
+        ),
+        AuditLog<T>,
+    >;
+
+    // Number of audit logs saved on a log file for a day, which is also the index of the next one
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_audit_log_entry_count)]
+    pub(super) type AuditLogEntryCountStorage<T: Config> = StorageDoubleMap<_, Blake2_128Concat, AuditLogFileName<T>, Twox64Concat, AuditLogDay, u32, ValueQuery>;
+
+    // Merkle tree of the audit logs of a log file for a day, the root is the default hash while there are none
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_audit_log_merkle_tree)]
+    pub(super) type AuditLogMerkleStorage<T: Config> = StorageDoubleMap<_, Blake2_128Concat, AuditLogFileName<T>, Twox64Concat, AuditLogDay, AuditLogMerkleTree<T>, ValueQuery>;
+
+    // Schema the audit logs saved on a log file must follow, if any
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_log_schema)]
+    pub(super) type AuditLogSchemaStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName<T>, AuditLogSchema<T>>;
+
+    // Latest link of the hash chain of every log file
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_audit_log_chain_head)]
+    pub(super) type AuditLogChainHeadStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName<T>, AuditLogChainHead<T>, ValueQuery>;
+
+    // Day and index within the day of the audit log at a sequence of the chain of a log file
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_audit_log_chain_location)]
+    pub(super) type AuditLogChainLocationStorage<T: Config> = StorageDoubleMap<_, Blake2_128Concat, AuditLogFileName<T>, Twox64Concat, u64, (AuditLogDay, u32)>;
+
+    // Number of days the audit logs of a log file are kept, forever when there is none
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_log_retention)]
+    pub(super) type AuditLogRetentionStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName<T>, u32>;
+
+    // Day the audit logs of a log file for a day are due to be pruned on
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_audit_log_prune_day)]
+    pub(super) type AuditLogPruneDayStorage<T: Config> = StorageDoubleMap<_, Blake2_128Concat, AuditLogFileName<T>, Twox64Concat, AuditLogDay, AuditLogDay>;
+
+    // Log files and days of the audit logs due to be pruned, keyed by the day they are due on
+    #[pallet::storage]
+    pub(super) type AuditLogPruneQueueStorage<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, AuditLogDay>,
+            NMapKey<Blake2_128Concat, AuditLogFileName<T>>,
+            NMapKey<Twox64Concat, AuditLogDay>,
+        ),
+        (),
+    >;
+
+    // Earliest day audit logs may be due to be pruned on, the days before have been pruned already
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_next_prune_day)]
+    pub(super) type AuditLogNextPruneDayStorage<T: Config> = StorageValue<_, AuditLogDay>;
+
+    // Encryption key registered by an account to read encrypted audit logs
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_encryption_key)]
+    pub(super) type AuditLogEncryptionKeyStorage<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, AuditLogEncryptionKey>;
+
+    // Reporter registered by an account
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_reporter)]
+    pub(super) type AuditLogReporterStorage<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, AuditLogReporter<T>>;
+
+    // Whether a log file only accepts audit logs from registered reporters that are not retired
+    #[pallet::storage]
+    #[pallet::getter(fn accepts_registered_reporters_only)]
+    pub(super) type AuditLogRegisteredReportersOnlyStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName<T>, bool, ValueQuery>;
+
+    // Whether the registered reporter saves audit logs without fees within its quota, as allowed by the feeless reporter origin
+    #[pallet::storage]
+    #[pallet::getter(fn is_feeless_reporter)]
+    pub(super) type AuditLogFeelessReporterStorage<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    // Delegates allowed to save audit logs on a log file without having a role on it
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_delegation)]
+    pub(super) type AuditLogDelegationStorage<T: Config> = StorageDoubleMap<_, Blake2_128Concat, AuditLogFileName<T>, Blake2_128Concat, T::AccountId, AuditLogDelegation<T>>;
+
+    // Rate limit of all the audit logs saved on a log file
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_log_rate_limit)]
+    pub(super) type AuditLogFileRateLimitStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName<T>, AuditLogRateLimit>;
+
+    // Rate limit of the audit logs saved by a reporter on a log file
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_reporter_rate_limit)]
+    pub(super) type AuditLogReporterRateLimitStorage<T: Config> = StorageDoubleMap<_, Blake2_128Concat, AuditLogFileName<T>, Blake2_128Concat, T::AccountId, AuditLogRateLimit>;
+
+    // Audit logs saved on a log file under its rate limit
+    #[pallet::storage]
+    pub(super) type AuditLogFileRateUsageStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName<T>, AuditLogRateUsage<T>, ValueQuery>;
+
+    // Audit logs saved by a reporter on a log file under its rate limit
+    #[pallet::storage]
+    pub(super) type AuditLogReporterRateUsageStorage<T: Config> = StorageDoubleMap<_, Blake2_128Concat, AuditLogFileName<T>, Blake2_128Concat, T::AccountId, AuditLogRateUsage<T>, ValueQuery>;
+
+    // Current key epoch of a log file, none until the first one is started
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_key_epoch)]
+    pub(super) type AuditLogKeyEpochStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName<T>, AuditLogKeyEpoch<T>>;
+
+    // Hash of the reason a log file is under legal hold, its audit logs can then be neither pruned, redacted nor removed
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_legal_hold)]
+    pub(super) type AuditLogLegalHoldStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName<T>, T::Hash>;
+
+    // Log files deleted whose audit logs are still being removed a page at a time, their name can't be used until then
+    #[pallet::storage]
+    pub(super) type AuditLogDeletedFileStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName<T>, ()>;
+
+    // Deposit reserved from an account for the audit logs it saved on a log file for a day
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_audit_log_deposit)]
+    pub(super) type AuditLogDepositStorage<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, AuditLogFileName<T>>,
+            NMapKey<Twox64Concat, AuditLogDay>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_audit_log_roles)]
+    pub(super) type AuditLogRoleStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName<T>, AuditLogRoles<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn retrieve_audit_log_open_for_claim)]
+    pub(super) type AuditLogOpenForClaimStorage<T: Config> = StorageMap<_, Blake2_128Concat, AuditLogFileName<T>, AuditLogOpenForClaim<T>, ValueQuery>;
+
+    // Log file names whose ownership claim expires at the given block number
+    #[pallet::storage]
+    pub(super) type AuditLogClaimExpiryStorage<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<AuditLogFileName<T>, T::MaxClaimsExpiringPerBlock>, ValueQuery>;
+   
+    #[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Event documentation should end with an array that provides descriptive details for event
+		/// parameters. [something, who]
+		AuditLogInformationStored(AuditLogFileName<T>, AuditLogDay, T::AccountId, Severity, AuditLogTags<T>, AuditLogAttributes<T>),
+        // T::AccountId is included to specify who claimed the open log
+        AuditLogClaimedForOwnership(AuditLogFileName<T>, T::AccountId),
+        // T::AccountId is included to specify who opened the log for claiming
+        AuditLogOpenedForOwnershipClaim(AuditLogFileName<T>, T::AccountId),
+        // T::AccountId is included to specify who the expired claim was assigned to
+        AuditLogClaimExpired(AuditLogFileName<T>, T::AccountId),
+        // T::AccountId is included to specify who cancelled the claim
+        AuditLogOwnershipClaimCancelled(AuditLogFileName<T>, T::AccountId),
+        // T::AccountId is included to specify who replaced the claim
+        AuditLogOwnershipClaimReplaced(AuditLogFileName<T>, T::AccountId),
+        // T::AccountId is included to specify who was granted the role
+        AuditLogRoleGranted(AuditLogFileName<T>, T::AccountId, AuditLogRole),
+        // T::AccountId is included to specify whose role was revoked
+        AuditLogRoleRevoked(AuditLogFileName<T>, T::AccountId),
+        // T::AccountId is included to specify who attested the logs of the day
+        AuditLogAttested(AuditLogFileName<T>, AuditLogDay, T::AccountId),
+        // First T::AccountId is the removed owner, the second one is who removed it
+        AuditLogOwnerRemoved(AuditLogFileName<T>, T::AccountId, T::AccountId),
+        // T::AccountId is included to specify who gave up the ownership
+        AuditLogOwnershipRenounced(AuditLogFileName<T>, T::AccountId),
+        // T::AccountId is included to specify who saved the batch, u32 is the number of audit logs it saved
+        AuditLogBatchStored(T::AccountId, u32),
+        // T::AccountId is included to specify who set the schema
+        AuditLogSchemaSet(AuditLogFileName<T>, T::AccountId),
+        // T::AccountId is included to specify who cleared the schema
+        AuditLogSchemaCleared(AuditLogFileName<T>, T::AccountId),
+        // T::AccountId is included to specify who the deposit for an audit log was reserved from
+        AuditLogDepositReserved(AuditLogFileName<T>, T::AccountId, BalanceOf<T>),
+        // T::AccountId is included to specify who the deposits for the removed audit logs were given back to
+        AuditLogDepositUnreserved(AuditLogFileName<T>, T::AccountId, BalanceOf<T>),
+        // T::AccountId is included to specify who deleted the log file
+        AuditLogFileDeleted(AuditLogFileName<T>, T::AccountId),
+        AuditLogFileCleared(AuditLogFileName<T>),
+        // T::AccountId is included to specify who set the retention period, in days, none meaning forever
+        AuditLogRetentionSet(AuditLogFileName<T>, T::AccountId, Option<u32>),
+        // u32 is the number of audit logs of the day that have been pruned
+        AuditLogsPruned(AuditLogFileName<T>, AuditLogDay, u32),
+        // T::Hash is the hash of the reason the log file is under legal hold
+        LegalHoldPlaced(AuditLogFileName<T>, T::Hash),
+        LegalHoldReleased(AuditLogFileName<T>),
+        // u32 is the index of the audit log within the day, T::AccountId is the admin who redacted it, if any,
+        // and T::Hash is the hash of the reason
+        AuditLogRedacted(AuditLogFileName<T>, AuditLogDay, u32, Option<T::AccountId>, T::Hash),
+        EncryptionKeyRegistered(T::AccountId, AuditLogEncryptionKey),
+        // u32 is the new key epoch, the content keys of the audit logs saved from now on are sealed for its readers
+        KeyEpochStarted(AuditLogFileName<T>, u32, AuditLogEpochReaders<T>),
+        // The first u32 is the index of the audit log within the day, the second the key epoch its content key is sealed in
+        EncryptedAuditLogStored(AuditLogFileName<T>, AuditLogDay, u32, u32),
+        ReporterRegistered(T::AccountId),
+        ReporterUpdated(T::AccountId),
+        ReporterRetired(T::AccountId),
+        // T::AccountId is the admin who set whether the log file only accepts registered reporters
+        RegisteredReportersOnlySet(AuditLogFileName<T>, T::AccountId, bool),
+        // T::AccountId is the delegate, followed by the block number the delegation expires at and its daily quota
+        DelegateAuthorized(AuditLogFileName<T>, T::AccountId, Option<T::BlockNumber>, Option<u32>),
+        DelegateRevoked(AuditLogFileName<T>, T::AccountId),
+        // T::AccountId is the reporter the rate limit is set for, none for the log file as a whole
+        AuditLogRateLimitSet(AuditLogFileName<T>, Option<T::AccountId>, Option<AuditLogRateLimit>),
+        // bool is whether the reporter saves audit logs without fees within its quota
+        FeelessReporterSet(T::AccountId, bool),
+	}
+
+    // Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+        AuditLogIdentifierCannotBeUsed,
+        NoRightsToOpenAuditLogForClaiming,
+        NotExistingAuditLogCantBeOpenForClaiming,
+        NotAuthorizedToClaimAuditLog,
+        AuditLogAlreadyOpenedForClaiming,
+        AuditLogCantBeFound,
+        AuditLogNotOpenedForClaiming,
+        AuditLogClaimHasExpired,
+        NoRightsToChangeAuditLogClaim,
+        NoRightsToManageAuditLogRoles,
+        NoRightsToAttestAuditLog,
+        AccountHasNoAuditLogRole,
+        AuditLogMustKeepAnAdmin,
+        AccountIsNotAuditLogOwner,
+        AuditLogFileNameTooLong,
+        AuditLogDateTooLong,
+        AuditLogTitleTooLong,
+        AuditLogContentTooLong,
+        AuditLogReporterTimestampTooLong,
+        AuditLogLocatorTooLong,
+        TooManyAuditLogOwners,
+        TooManyAuditLogEntriesForDate,
+        TooManyClaimsExpiringInBlock,
+        EmptyAuditLogBatch,
+        TooManyAuditLogsInBatch,
+        TooManyAuditLogTags,
+        AuditLogTagTooLong,
+        TooManyAuditLogAttributes,
+        AuditLogAttributeKeyTooLong,
+        AuditLogAttributeValueTooLong,
+        AuditLogMissingRequiredAttribute,
+        AuditLogSeverityNotAllowedBySchema,
+        AuditLogContentTooLongForSchema,
+        AuditLogContentTypeNotAllowedBySchema,
+        AuditLogSchemaCantBeFound,
+        TooManyAllowedSeverities,
+        InsufficientBalanceForDeposit,
+        InvalidRetentionPeriod,
+        LogFileUnderLegalHold,
+        LogFileNotUnderLegalHold,
+        AuditLogAlreadyRedacted,
+        TooManyReaders,
+        DuplicateReader,
+        NoReaders,
+        ReaderHasNoEncryptionKey,
+        ReadersNotInKeyEpoch,
+        ReporterFieldTooLong,
+        ReporterAlreadyRegistered,
+        ReporterNotRegistered,
+        ReporterIsRetired,
+        DelegationExpired,
+        DelegateQuotaExceeded,
+        DelegationCantBeFound,
+        RateLimitExceeded,
+        LogFileBeingDeleted,
+        LogDayCountTooLow
+	}
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            // Close the ownership claims that were left unclaimed within the claim window
+            Self::expire_ownership_claims(now)
+        }
+
+        fn on_idle(_now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            // Remove the audit logs kept longer than the retention period of their log file
+            let used_weight = Self::prune_expired_audit_logs(remaining_weight);
+            // Then what remains of the deleted log files, with the weight left
+            used_weight.saturating_add(Self::clear_deleted_log_files(remaining_weight.saturating_sub(used_weight)))
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            crate::migrations::migrate::<T>()
+        }
+    }
+
+    // Dispatchable functions allows users to interact with the pallet and invoke state changes.
+	// These functions materialize as "extrinsics", which are often compared to transactions.
+	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// An example dispatchable that takes a singles value as a parameter, writes the value to
+		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
+		/// To add audit log
+        #[pallet::weight(T::WeightInfo::save_audit_log((log_claimed_date, log_title, log_content, log_reporter_timestamp).encoded_size() as u32))]
+        #[transactional]
+        pub fn save_audit_log(origin: OriginFor<T>, log_file_name: Vec<u8>, log_claimed_date: Option<Vec<u8>>, log_title: Vec<u8>, log_content: Vec<u8>, log_reporter_timestamp: Vec<u8>) -> DispatchResultWithPostInfo {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let content = Self::plain_content(log_content)?;
+            let (log_file_name, _, _) = Self::save_log(sender.clone(), log_file_name, log_claimed_date, log_title, content, log_reporter_timestamp, Default::default())?;
+
+            // Return a successful DispatchResultWithPostInfo, refunding the fee of the feeless reporters
+            Ok(Self::audit_log_fee(&log_file_name, &sender).into())
+        }
+
+        /// To add an audit log with a severity, tags and key/value attributes
+        #[pallet::weight(T::WeightInfo::save_audit_log((log_claimed_date, log_title, log_content, log_reporter_timestamp, log_metadata).encoded_size() as u32))]
+        #[transactional]
+        pub fn save_structured_audit_log(origin: OriginFor<T>, log_file_name: Vec<u8>, log_claimed_date: Option<Vec<u8>>, log_title: Vec<u8>, log_content: Vec<u8>, log_reporter_timestamp: Vec<u8>, log_metadata: AuditLogMetadata) -> DispatchResultWithPostInfo {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let content = Self::plain_content(log_content)?;
+            let (log_file_name, _, _) = Self::save_log(sender.clone(), log_file_name, log_claimed_date, log_title, content, log_reporter_timestamp, log_metadata)?;
+
+            // Return a successful DispatchResultWithPostInfo, refunding the fee of the feeless reporters
+            Ok(Self::audit_log_fee(&log_file_name, &sender).into())
+        }
+
+        /// To add an audit log whose content is kept off-chain, only its digest and length are stored
+        #[pallet::weight(T::WeightInfo::save_audit_log((log_claimed_date, log_title, log_content_locator, log_reporter_timestamp).encoded_size() as u32))]
+        #[transactional]
+        pub fn save_audit_log_hash(origin: OriginFor<T>, log_file_name: Vec<u8>, log_claimed_date: Option<Vec<u8>>, log_title: Vec<u8>, log_content_digest: T::Hash, log_content_length: u32, log_content_locator: Option<Vec<u8>>, log_reporter_timestamp: Vec<u8>) -> DispatchResultWithPostInfo {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let content = Self::hashed_content(log_content_digest, log_content_length, log_content_locator)?;
+            let (log_file_name, _, _) = Self::save_log(sender.clone(), log_file_name, log_claimed_date, log_title, content, log_reporter_timestamp, Default::default())?;
+
+            // Return a successful DispatchResultWithPostInfo, refunding the fee of the feeless reporters
+            Ok(Self::audit_log_fee(&log_file_name, &sender).into())
+        }
+
+        /// To add an audit log whose content is encrypted, along with its content key sealed for each of its readers
+        #[pallet::weight(T::WeightInfo::save_audit_log((log_claimed_date, log_title, log_ciphertext, log_wrapped_keys, log_reporter_timestamp).encoded_size() as u32)
+            // Reading the key epoch of the log file, or the encryption keys of the readers when it has none
+            .saturating_add(T::DbWeight::get().reads(1 + log_wrapped_keys.len() as Weight)))]
+        #[transactional]
+        pub fn save_encrypted_audit_log(
+            origin: OriginFor<T>,
+            log_file_name: Vec<u8>,
+            log_claimed_date: Option<Vec<u8>>,
+            log_title: Vec<u8>,
+            log_ciphertext: Vec<u8>,
+            log_nonce: AuditLogNonce,
+            log_ephemeral_public_key: AuditLogEncryptionKey,
+            log_wrapped_keys: Vec<(T::AccountId, AuditLogWrappedKey)>,
+            log_reporter_timestamp: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let (content, epoch) = Self::encrypted_content(&log_file_name, log_ciphertext, log_nonce, log_ephemeral_public_key, log_wrapped_keys)?;
+            let (log_file_name, log_day, entry_index) = Self::save_log(sender.clone(), log_file_name, log_claimed_date, log_title, content, log_reporter_timestamp, Default::default())?;
+            let fee = Self::audit_log_fee(&log_file_name, &sender);
+
+            // Emit the event telling the readers which of their encryption keys the content key is sealed with
+            Self::deposit_event(Event::EncryptedAuditLogStored(log_file_name, log_day, entry_index, epoch));
+
+            // Return a successful DispatchResultWithPostInfo, refunding the fee of the feeless reporters
+            Ok(fee.into())
+        }
+
+        /// To set the x25519 public key the content keys of the encrypted audit logs readable by the account are sealed with
+        #[pallet::weight(T::WeightInfo::register_encryption_key())]
+        pub fn register_encryption_key(origin: OriginFor<T>, encryption_key: AuditLogEncryptionKey) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            AuditLogEncryptionKeyStorage::<T>::insert(&sender, encryption_key);
+
+            // Emit the event that the encryption key of the account has been registered
+            Self::deposit_event(Event::EncryptionKeyRegistered(sender, encryption_key));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To seal the content keys of the encrypted audit logs saved from now on for a new set of readers, with their
+        /// current encryption keys. The audit logs saved before stay readable by the readers they were sealed for
+        #[pallet::weight(T::WeightInfo::start_key_epoch(readers.len() as u32))]
+        pub fn start_key_epoch(origin: OriginFor<T>, log_file_name: Vec<u8>, readers: Vec<T::AccountId>) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            Self::ensure_log_admin(&log_file_name, &sender)?;
+            frame_support::ensure!(readers.len() <= T::MaxReaders::get() as usize, Error::<T>::TooManyReaders);
+            frame_support::ensure!(!readers.is_empty(), Error::<T>::NoReaders);
+
+            let mut epoch_readers = Vec::with_capacity(readers.len());
+            for (index, reader) in readers.iter().enumerate() {
+                frame_support::ensure!(!readers[..index].contains(reader), Error::<T>::DuplicateReader);
+                let encryption_key = AuditLogEncryptionKeyStorage::<T>::get(reader).ok_or(Error::<T>::ReaderHasNoEncryptionKey)?;
+                epoch_readers.push((reader.clone(), encryption_key));
+            }
+            let epoch_readers: AuditLogEpochReaders<T> = epoch_readers.try_into().map_err(|_| Error::<T>::TooManyReaders)?;
+            let epoch = AuditLogKeyEpochStorage::<T>::get(&log_file_name).map_or(1, |key_epoch| key_epoch.get_epoch() + 1);
+
+            AuditLogKeyEpochStorage::<T>::insert(&log_file_name, AuditLogKeyEpoch::new(epoch, epoch_readers.clone()));
+
+            // Emit the event that the log file has a new key epoch
+            Self::deposit_event(Event::KeyEpochStarted(log_file_name, epoch, epoch_readers));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To register the system the sender saves audit logs for
+        #[pallet::weight(T::WeightInfo::register_reporter())]
+        pub fn register_reporter(origin: OriginFor<T>, system_name: Vec<u8>, environment: Vec<u8>, software_version: Vec<u8>, contact_hash: T::Hash) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            frame_support::ensure!(!AuditLogReporterStorage::<T>::contains_key(&sender), Error::<T>::ReporterAlreadyRegistered);
+            let reporter = Self::new_reporter(&sender, system_name, environment, software_version, contact_hash)?;
+            AuditLogReporterStorage::<T>::insert(&sender, reporter);
+
+            // Emit the event that the reporter has been registered
+            Self::deposit_event(Event::ReporterRegistered(sender));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To change the system the sender saves audit logs for, such as when a new software version is deployed
+        #[pallet::weight(T::WeightInfo::update_reporter())]
+        pub fn update_reporter(origin: OriginFor<T>, system_name: Vec<u8>, environment: Vec<u8>, software_version: Vec<u8>, contact_hash: T::Hash) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            Self::ensure_active_reporter(&sender)?;
+            let reporter = Self::new_reporter(&sender, system_name, environment, software_version, contact_hash)?;
+            AuditLogReporterStorage::<T>::insert(&sender, reporter);
+
+            // Emit the event that the reporter has been updated
+            Self::deposit_event(Event::ReporterUpdated(sender));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To stop saving audit logs as a registered reporter, the registration being kept for the audit logs saved before
+        #[pallet::weight(T::WeightInfo::retire_reporter())]
+        pub fn retire_reporter(origin: OriginFor<T>) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            Self::ensure_active_reporter(&sender)?;
+            AuditLogReporterStorage::<T>::mutate(&sender, |reporter| {
+                if let Some(reporter) = reporter {
+                    reporter.retire();
+                }
+            });
+
+            // Emit the event that the reporter has been retired
+            Self::deposit_event(Event::ReporterRetired(sender));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To only accept audit logs on a log file from registered reporters that are not retired, or from any writer
+        #[pallet::weight(T::WeightInfo::set_registered_reporters_only())]
+        pub fn set_registered_reporters_only(origin: OriginFor<T>, log_file_name: Vec<u8>, registered_reporters_only: bool) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            Self::ensure_log_admin(&log_file_name, &sender)?;
+
+            if registered_reporters_only {
+                AuditLogRegisteredReportersOnlyStorage::<T>::insert(&log_file_name, true);
+            } else {
+                AuditLogRegisteredReportersOnlyStorage::<T>::remove(&log_file_name);
+            }
+
+            // Emit the event that the reporters accepted by the log file have been set
+            Self::deposit_event(Event::RegisteredReportersOnlySet(log_file_name, sender, registered_reporters_only));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To let a delegate save audit logs on a log file without holding a key of its admins, until the expiry
+        /// block and within the daily quota, if any. Authorizing a delegate again replaces its delegation
+        #[pallet::weight(T::WeightInfo::authorize_delegate())]
+        pub fn authorize_delegate(origin: OriginFor<T>, log_file_name: Vec<u8>, delegate: T::AccountId, expires_at: Option<T::BlockNumber>, daily_quota: Option<u32>) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            Self::ensure_log_admin(&log_file_name, &sender)?;
+
+            AuditLogDelegationStorage::<T>::insert(&log_file_name, &delegate, AuditLogDelegation::new(expires_at, daily_quota));
+
+            // Emit the event that the delegate can save audit logs on the log file
+            Self::deposit_event(Event::DelegateAuthorized(log_file_name, delegate, expires_at, daily_quota));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To stop a delegate from saving audit logs on a log file
+        #[pallet::weight(T::WeightInfo::revoke_delegate())]
+        pub fn revoke_delegate(origin: OriginFor<T>, log_file_name: Vec<u8>, delegate: T::AccountId) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            Self::ensure_log_admin(&log_file_name, &sender)?;
+            frame_support::ensure!(AuditLogDelegationStorage::<T>::contains_key(&log_file_name, &delegate), Error::<T>::DelegationCantBeFound);
+
+            AuditLogDelegationStorage::<T>::remove(&log_file_name, &delegate);
+
+            // Emit the event that the delegate can no longer save audit logs on the log file
+            Self::deposit_event(Event::DelegateRevoked(log_file_name, delegate));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To limit the audit logs saved on a log file, or by one reporter on it, per block and in bytes per window of blocks
+        #[pallet::weight(T::WeightInfo::set_log_rate_limit())]
+        pub fn set_log_rate_limit(origin: OriginFor<T>, log_file_name: Vec<u8>, reporter: Option<T::AccountId>, rate_limit: Option<AuditLogRateLimit>) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            Self::ensure_log_admin(&log_file_name, &sender)?;
+
+            match (&reporter, &rate_limit) {
+                (Some(reporter), Some(rate_limit)) => AuditLogReporterRateLimitStorage::<T>::insert(&log_file_name, reporter, rate_limit),
+                (Some(reporter), None) => {
+                    AuditLogReporterRateLimitStorage::<T>::remove(&log_file_name, reporter);
+                    AuditLogReporterRateUsageStorage::<T>::remove(&log_file_name, reporter);
+                },
+                (None, Some(rate_limit)) => AuditLogFileRateLimitStorage::<T>::insert(&log_file_name, rate_limit),
+                (None, None) => {
+                    AuditLogFileRateLimitStorage::<T>::remove(&log_file_name);
+                    AuditLogFileRateUsageStorage::<T>::remove(&log_file_name);
+                },
+            }
+
+            // Emit the event that the rate limit has been set
+            Self::deposit_event(Event::AuditLogRateLimitSet(log_file_name, reporter, rate_limit));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To add many audit logs at once, on one or more log files. Either all of them are saved or none
+        #[pallet::weight(T::WeightInfo::save_audit_logs_batch(
+            audit_logs.len() as u32,
+            AuditLogBatchEntry::log_file_count(audit_logs),
+            audit_logs.iter().map(|audit_log| audit_log.encoded_size() as u32).sum(),
+        ))]
+        #[transactional]
+        pub fn save_audit_logs_batch(origin: OriginFor<T>, audit_logs: Vec<AuditLogBatchEntry>) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            frame_support::ensure!(!audit_logs.is_empty(), <Error<T>>::EmptyAuditLogBatch);
+            frame_support::ensure!(audit_logs.len() <= T::MaxBatchSize::get() as usize, <Error<T>>::TooManyAuditLogsInBatch);
+
+            // Rights are only checked and schemas only read the first time a log file appears in the batch, delegates
+            // having every audit log counted
+            let mut writable_log_files: Vec<(AuditLogFileName<T>, Option<AuditLogSchema<T>>, bool)> = Vec::new();
+            let audit_log_count = audit_logs.len() as u32;
+            for entry in audit_logs {
+                let log_file_name = Self::bounded::<T::MaxFileNameLen>(entry.log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+                let log_day = Self::current_day();
+                let audit_log = Self::new_audit_log(
+                    &sender,
+                    entry.log_claimed_date,
+                    entry.log_title,
+                    AuditLogContent::Plain(Self::bounded(entry.log_content, Error::<T>::AuditLogContentTooLong)?),
+                    entry.log_reporter_timestamp,
+                    entry.log_metadata,
+                )?;
+
+                let schema = match writable_log_files.iter().find(|(writable_log_file_name, _, _)| writable_log_file_name == &log_file_name) {
+                    Some((_, schema, as_delegate)) => {
+                        if *as_delegate {
+                            Self::record_delegate_entry(&log_file_name, &sender)?;
+                        }
+                        schema.clone()
+                    },
+                    None => {
+                        let as_delegate = Self::ensure_can_write(&log_file_name, &sender)?;
+                        let schema = AuditLogSchemaStorage::<T>::get(&log_file_name);
+                        writable_log_files.push((log_file_name.clone(), schema.clone(), as_delegate));
+                        schema
+                    }
+                };
+                if let Some(schema) = schema {
+                    schema.check(&audit_log)?;
+                }
+                Self::record_rate_usage(&log_file_name, &sender, &audit_log)?;
+                Self::reserve_deposit(&log_file_name, log_day, &sender, &audit_log)?;
+                let stored_event = Self::stored_event(&log_file_name, log_day, &audit_log);
+                Self::append_audit_log(&log_file_name, log_day, audit_log)?;
+
+                // Emit the event that audit log has been added in chain
+                Self::deposit_event(stored_event);
+            }
+
+            // Emit the event that the whole batch has been added in chain
+            Self::deposit_event(Event::AuditLogBatchStored(sender, audit_log_count));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::claim_log(T::MaxOwners::get()))]
+        pub fn claim_log(origin: OriginFor<T>, log_file_name: Vec<u8>) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let claimer = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+
+            // Checks if the audit log is open for claiming, and returns the assigned claimer for the log available for claiming
+            let audit_log_open_for_claim = AuditLogOpenForClaimStorage::<T>::try_get(&log_file_name);
+            match audit_log_open_for_claim {
+                Ok(audit_log_for_claim) => {
+
+                    // Checks if the log file name claiming has expired
+                    frame_support::ensure!(<frame_system::Pallet<T>>::block_number() < audit_log_for_claim.expires_at, <Error<T>>::AuditLogClaimHasExpired);
+
+                    // Checks if claimer is the assigned claimer
+                    frame_support::ensure!(&claimer == &audit_log_for_claim.assigned_claimer, <Error<T>>::NotAuthorizedToClaimAuditLog);
+
+                    Self::add_claimer_as_log_owner(&log_file_name, claimer.clone())?;
+
+                    // Emit the event that the audit log has been claimed
+                    Self::deposit_event(Event::AuditLogClaimedForOwnership(log_file_name, claimer));
+                }
+                Err(_error) => {
+                    // Raise error when the log file is not opened for claiming
+                    frame_support::ensure!(0 == 1, <Error<T>>::AuditLogNotOpenedForClaiming);
+                }
+            }
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        // TODO: Clean up the code for this function if possible 
+        #[pallet::weight(T::WeightInfo::open_log_for_ownership_claim(T::MaxOwners::get(), T::MaxClaimsExpiringPerBlock::get()))]
+        pub fn open_log_for_ownership_claim(origin: OriginFor<T>, log_file_name: Vec<u8>, claimer_pubkey: [u8; 32]) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+
+            // Check if log is owned by someone
+            let log_roles = AuditLogRoleStorage::<T>::try_get(&log_file_name);
+            match log_roles {
+                // Log is owned by someone
+                Ok(roles) => {
+                    // Check if log is owned by the function caller
+                    if Self::role_of(&roles, &sender).map_or(false, |role| role.can_manage()) {
+                        // Check if the file is already open for claiming. Only one claiming can happen at a time
+                        let is_log_already_open = AuditLogOpenForClaimStorage::<T>::try_get(&log_file_name);
+                        match is_log_already_open {
+                            Ok(_is_log_already_open) => {
+                               // Raise an error that the log is already opened
+                                frame_support::ensure!(0 == 1, <Error<T>>::AuditLogAlreadyOpenedForClaiming);
+                            }
+                            Err(_error) => {
+                                Self::open_log_for_claim(&log_file_name, claimer_pubkey)?;
+
+                                // Emit the event that an audit log has been opened for claiming
+                                Self::deposit_event(Event::AuditLogOpenedForOwnershipClaim(log_file_name, sender));
+                            }
+                        }
+                    } else {
+                        frame_support::ensure!(0 == 1, <Error<T>>::NoRightsToOpenAuditLogForClaiming);
+                    }
+                }
+                Err(_error) => {
+                    // The log opened does not exist since it has no owner, Raise an error
+                    frame_support::ensure!(0 == 1, <Error<T>>::AuditLogCantBeFound);
+                }
+            }
+            
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::cancel_ownership_claim())]
+        pub fn cancel_ownership_claim(origin: OriginFor<T>, log_file_name: Vec<u8>) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            Self::ensure_can_change_log_claim(&log_file_name, &sender)?;
+
+            Self::close_log_for_claim(&log_file_name);
+
+            // Emit the event that the ownership claim has been withdrawn
+            Self::deposit_event(Event::AuditLogOwnershipClaimCancelled(log_file_name, sender));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::replace_ownership_claim())]
+        pub fn replace_ownership_claim(origin: OriginFor<T>, log_file_name: Vec<u8>, claimer_pubkey: [u8; 32]) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            Self::ensure_can_change_log_claim(&log_file_name, &sender)?;
+
+            // The replacing claim takes the place of the pending one and gets a claim window of its own
+            Self::close_log_for_claim(&log_file_name);
+            Self::open_log_for_claim(&log_file_name, claimer_pubkey)?;
+
+            // Emit the event that the ownership claim now has another assigned claimer
+            Self::deposit_event(Event::AuditLogOwnershipClaimReplaced(log_file_name, sender));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_log_schema(required_attribute_keys.len() as u32))]
+        pub fn set_log_schema(origin: OriginFor<T>, log_file_name: Vec<u8>, required_attribute_keys: Vec<Vec<u8>>, allowed_severities: Vec<Severity>, max_content_length: Option<u32>, content_type: AuditLogContentType) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            Self::ensure_log_admin(&log_file_name, &sender)?;
+
+            let required_attribute_keys = required_attribute_keys
+                .into_iter()
+                .map(|key| Self::bounded(key, Error::<T>::AuditLogAttributeKeyTooLong))
+                .collect::<Result<Vec<_>, _>>()?;
+            let schema = AuditLogSchema::new(
+                required_attribute_keys.try_into().map_err(|_| Error::<T>::TooManyAuditLogAttributes)?,
+                allowed_severities.try_into().map_err(|_| Error::<T>::TooManyAllowedSeverities)?,
+                max_content_length,
+                content_type,
+            );
+
+            // Replaces the schema the log file may already have, audit logs saved before are left as is
+            AuditLogSchemaStorage::<T>::insert(&log_file_name, schema);
+
+            // Emit the event that the log file has a schema
+            Self::deposit_event(Event::AuditLogSchemaSet(log_file_name, sender));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::clear_log_schema())]
+        pub fn clear_log_schema(origin: OriginFor<T>, log_file_name: Vec<u8>) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            Self::ensure_log_admin(&log_file_name, &sender)?;
+            frame_support::ensure!(AuditLogSchemaStorage::<T>::contains_key(&log_file_name), <Error<T>>::AuditLogSchemaCantBeFound);
+
+            AuditLogSchemaStorage::<T>::remove(&log_file_name);
+
+            // Emit the event that the log file has no schema anymore
+            Self::deposit_event(Event::AuditLogSchemaCleared(log_file_name, sender));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To keep the audit logs of a log file for a number of days, or forever when there is none.
+        /// `log_day_count` is at least the number of days the log file has audit logs for, as given by `log_day_count`
+        /// of the runtime API, each of them being rescheduled
+        #[pallet::weight(T::WeightInfo::set_log_retention(*log_day_count))]
+        pub fn set_log_retention(origin: OriginFor<T>, log_file_name: Vec<u8>, retention_days: Option<u32>, log_day_count: u32) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            Self::ensure_log_admin(&log_file_name, &sender)?;
+            // Audit logs are kept at least for the day they are saved on
+            frame_support::ensure!(retention_days != Some(0), <Error<T>>::InvalidRetentionPeriod);
+            let log_days = Self::log_days_within(&log_file_name, log_day_count)?;
+
+            match retention_days {
+                Some(retention_days) => AuditLogRetentionStorage::<T>::insert(&log_file_name, retention_days),
+                None => AuditLogRetentionStorage::<T>::remove(&log_file_name),
+            }
+
+            // The days already kept are due to be pruned according to the new retention period
+            Self::reschedule_pruning(&log_file_name, log_days);
+
+            // Emit the event that the retention period of the log file has been set
+            Self::deposit_event(Event::AuditLogRetentionSet(log_file_name, sender, retention_days));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::grant_log_role(T::MaxOwners::get()))]
+        pub fn grant_log_role(origin: OriginFor<T>, log_file_name: Vec<u8>, account: T::AccountId, role: AuditLogRole) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            let mut roles = Self::ensure_log_admin(&log_file_name, &sender)?;
+
+            // Granting a role to a member replaces the role it had
+            match roles.iter().position(|(member, _)| member == &account) {
+                Some(index) => roles[index].1 = role,
+                None => roles.push((account.clone(), role)),
+            }
+            Self::ensure_has_admin(&roles)?;
+            Self::store_roles(&log_file_name, roles)?;
+
+            // Emit the event that the account has been granted a role on the log
+            Self::deposit_event(Event::AuditLogRoleGranted(log_file_name, account, role));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::revoke_log_role(T::MaxOwners::get()))]
+        pub fn revoke_log_role(origin: OriginFor<T>, log_file_name: Vec<u8>, account: T::AccountId) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            let mut roles = Self::ensure_log_admin(&log_file_name, &sender)?;
+
+            let index = roles.iter().position(|(member, _)| member == &account).ok_or(Error::<T>::AccountHasNoAuditLogRole)?;
+            roles.remove(index);
+            Self::ensure_has_admin(&roles)?;
+            Self::store_roles(&log_file_name, roles)?;
+
+            // Emit the event that the account has no role on the log anymore
+            Self::deposit_event(Event::AuditLogRoleRevoked(log_file_name, account));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::remove_owner(T::MaxOwners::get()))]
+        pub fn remove_owner(origin: OriginFor<T>, log_file_name: Vec<u8>, owner: T::AccountId) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            let roles = Self::ensure_log_admin(&log_file_name, &sender)?;
+            Self::remove_log_owner(&log_file_name, roles, &owner)?;
+
+            // Emit the event that the owner has been removed from the log
+            Self::deposit_event(Event::AuditLogOwnerRemoved(log_file_name, owner, sender));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::renounce_ownership(T::MaxOwners::get()))]
+        pub fn renounce_ownership(origin: OriginFor<T>, log_file_name: Vec<u8>) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            let roles = AuditLogRoleStorage::<T>::try_get(&log_file_name).map_err(|_| Error::<T>::AuditLogCantBeFound)?;
+            Self::remove_log_owner(&log_file_name, roles.into_inner(), &sender)?;
+
+            // Emit the event that the sender is not an owner of the log anymore
+            Self::deposit_event(Event::AuditLogOwnershipRenounced(log_file_name, sender));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::attest_audit_log())]
+        pub fn attest_audit_log(origin: OriginFor<T>, log_file_name: Vec<u8>, log_day: AuditLogDay) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+
+            let roles = AuditLogRoleStorage::<T>::try_get(&log_file_name).map_err(|_| Error::<T>::AuditLogCantBeFound)?;
+            frame_support::ensure!(Self::role_of(&roles, &sender).map_or(false, |role| role.can_attest()), <Error<T>>::NoRightsToAttestAuditLog);
+            frame_support::ensure!(AuditLogEntryCountStorage::<T>::get(&log_file_name, log_day) > 0, <Error<T>>::AuditLogCantBeFound);
+
+            // Emit the event that the logs of the day have been attested
+            Self::deposit_event(Event::AuditLogAttested(log_file_name, log_day, sender));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To remove a log file with all of its audit logs, giving the deposits back to the accounts that saved them.
+        /// The audit logs are removed a page at a time when blocks have weight left
+        #[pallet::weight(T::WeightInfo::delete_log_file())]
+        pub fn delete_log_file(origin: OriginFor<T>, log_file_name: Vec<u8>) -> DispatchResult {
+
+            // The dispatch origin of this call must be a participant.
+            let sender = ensure_signed(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            Self::ensure_log_admin(&log_file_name, &sender)?;
+            // Not even the owners can remove the audit logs of a log file under legal hold
+            Self::ensure_not_under_legal_hold(&log_file_name)?;
+
+            Self::remove_log_file(&log_file_name);
+
+            // Emit the event that the log file has been deleted
+            Self::deposit_event(Event::AuditLogFileDeleted(log_file_name, sender));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To erase the content of an audit log, keeping its digest as proof that it existed and was redacted
+        #[pallet::weight(T::WeightInfo::redact_audit_log_entry(T::MaxContentLen::get()))]
+        pub fn redact_audit_log_entry(origin: OriginFor<T>, log_file_name: Vec<u8>, log_day: AuditLogDay, entry_index: u32, reason_hash: T::Hash) -> DispatchResult {
+
+            // The dispatch origin of this call must be the privacy officer origin or an admin of the log file.
+            let redacted_by = match T::PrivacyOfficerOrigin::try_origin(origin) {
+                Ok(_) => None,
+                Err(origin) => Some(ensure_signed(origin)?),
+            };
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            if let Some(sender) = &redacted_by {
+                Self::ensure_log_admin(&log_file_name, sender)?;
+            }
+            Self::ensure_not_under_legal_hold(&log_file_name)?;
+
+            AuditLogEntryStorage::<T>::try_mutate((&log_file_name, log_day, entry_index), |audit_log| -> DispatchResult {
+                let audit_log = audit_log.as_mut().ok_or(Error::<T>::AuditLogCantBeFound)?;
+                frame_support::ensure!(!audit_log.is_redacted(), Error::<T>::AuditLogAlreadyRedacted);
+                audit_log.redact(redacted_by.clone(), <pallet_timestamp::Pallet<T>>::get(), reason_hash);
+                Ok(())
+            })?;
+
+            // Emit the event that the audit log has been redacted
+            Self::deposit_event(Event::AuditLogRedacted(log_file_name, log_day, entry_index, redacted_by, reason_hash));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To freeze the audit logs of a log file, so that they can be neither pruned, redacted nor removed until the hold is released
+        #[pallet::weight(T::WeightInfo::place_legal_hold())]
+        pub fn place_legal_hold(origin: OriginFor<T>, log_file_name: Vec<u8>, reason_hash: T::Hash) -> DispatchResult {
+
+            // The dispatch origin of this call must be the legal hold origin.
+            T::LegalHoldOrigin::ensure_origin(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            frame_support::ensure!(AuditLogRoleStorage::<T>::contains_key(&log_file_name), Error::<T>::AuditLogCantBeFound);
+            Self::ensure_not_under_legal_hold(&log_file_name)?;
+
+            AuditLogLegalHoldStorage::<T>::insert(&log_file_name, reason_hash);
+
+            // Emit the event that the log file is under legal hold
+            Self::deposit_event(Event::LegalHoldPlaced(log_file_name, reason_hash));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To release the legal hold on a log file, its audit logs over the retention period are then pruned again.
+        /// `log_day_count` is at least the number of days the log file has audit logs for, as for `set_log_retention`
+        #[pallet::weight(T::WeightInfo::release_legal_hold(*log_day_count))]
+        pub fn release_legal_hold(origin: OriginFor<T>, log_file_name: Vec<u8>, log_day_count: u32) -> DispatchResult {
+
+            // The dispatch origin of this call must be the legal hold origin.
+            T::LegalHoldOrigin::ensure_origin(origin)?;
+
+            let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+            frame_support::ensure!(AuditLogLegalHoldStorage::<T>::contains_key(&log_file_name), Error::<T>::LogFileNotUnderLegalHold);
+            let log_days = Self::log_days_within(&log_file_name, log_day_count)?;
+
+            AuditLogLegalHoldStorage::<T>::remove(&log_file_name);
+            // The days skipped by the pruning while on hold are due again
+            Self::reschedule_pruning(&log_file_name, log_days);
+
+            // Emit the event that the legal hold on the log file has been released
+            Self::deposit_event(Event::LegalHoldReleased(log_file_name));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+
+        /// To let a registered reporter save audit logs without fees on the log files where it has a rate limit quota,
+        /// as long as they fit in it, or to make it pay fees again
+        #[pallet::weight(T::WeightInfo::set_feeless_reporter())]
+        pub fn set_feeless_reporter(origin: OriginFor<T>, reporter: T::AccountId, feeless: bool) -> DispatchResult {
+
+            // The dispatch origin of this call must be the feeless reporter origin.
+            T::FeelessReporterOrigin::ensure_origin(origin)?;
+
+            if feeless {
+                AuditLogFeelessReporterStorage::<T>::insert(&reporter, true);
+            } else {
+                AuditLogFeelessReporterStorage::<T>::remove(&reporter);
+            }
+
+            // Emit the event that the reporter saves audit logs with or without fees
+            Self::deposit_event(Event::FeelessReporterSet(reporter, feeless));
+
+            // Return a successful DispatchResult
+            Ok(())
+        }
+	}
+}
+
+impl<T: Config> Pallet<T> {
+
+    /// Returns the audit logs saved on the log file for the day, in the order they were saved.
+    pub fn retrieve_audit_log(log_file_name: AuditLogFileName<T>, log_day: AuditLogDay) -> Vec<AuditLog<T>> {
+        let entry_count = AuditLogEntryCountStorage::<T>::get(&log_file_name, log_day);
+        (0..entry_count)
+            .filter_map(|index| AuditLogEntryStorage::<T>::get((&log_file_name, log_day, index)))
+            .collect()
+    }
+
+    /// Returns the audit logs saved on the log file for the day that are at least as severe as the given severity,
+    /// have the given tag and the given attribute, each filter being optional.
+    pub fn retrieve_audit_logs_filtered(log_file_name: Vec<u8>, log_day: AuditLogDay, min_severity: Option<Severity>, tag: Option<Vec<u8>>, attribute: Option<(Vec<u8>, Vec<u8>)>) -> Vec<AuditLog<T>> {
+        Self::retrieve_audit_logs(log_file_name, log_day)
+            .into_iter()
+            .filter(|audit_log| min_severity.map_or(true, |min_severity| audit_log.clone().get_severity() >= min_severity))
+            .filter(|audit_log| tag.as_ref().map_or(true, |tag| audit_log.has_tag(tag)))
+            .filter(|audit_log| attribute.as_ref().map_or(true, |(key, value)| audit_log.has_attribute(key, value)))
+            .collect()
+    }
+
+    /// Same as `retrieve_audit_log`, for callers outside of the runtime holding an unbounded log file name.
+    pub fn retrieve_audit_logs(log_file_name: Vec<u8>, log_day: AuditLogDay) -> Vec<AuditLog<T>> {
+        match log_file_name.try_into() {
+            Ok(log_file_name) => Self::retrieve_audit_log(log_file_name, log_day),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Checks the payload against the content of the audit log at the index of the day, false if there is no such audit log.
+    pub fn verify_audit_log_content(log_file_name: Vec<u8>, log_day: AuditLogDay, entry_index: u32, payload: Vec<u8>) -> bool {
+        let log_file_name: AuditLogFileName<T> = match log_file_name.try_into() {
+            Ok(log_file_name) => log_file_name,
+            Err(_) => return false,
+        };
+        AuditLogEntryStorage::<T>::get((&log_file_name, log_day, entry_index))
+            .map_or(false, |audit_log| audit_log.get_content().matches(&payload))
+    }
+
+    /// Returns the audit logs of the log file from one sequence of its chain to another, both included.
+    ///
+    /// Stops at the first sequence without an audit log, so the result can be shorter than asked.
+    pub fn retrieve_audit_log_chain(log_file_name: Vec<u8>, from: u64, to: u64) -> Vec<AuditLog<T>> {
+        let log_file_name: AuditLogFileName<T> = match log_file_name.try_into() {
+            Ok(log_file_name) => log_file_name,
+            Err(_) => return Vec::new(),
+        };
+        (from..=to)
+            .map(|sequence| Self::chain_entry(&log_file_name, sequence))
+            .take_while(Option::is_some)
+            .flatten()
+            .collect()
+    }
+
+    /// Checks that the audit logs of the log file from one sequence of its chain to another, both included,
+    /// are linked to each other as well as to the audit logs just before and after them.
+    pub fn verify_chain(log_file_name: Vec<u8>, from: u64, to: u64) -> bool {
+        let log_file_name: AuditLogFileName<T> = match log_file_name.try_into() {
+            Ok(log_file_name) => log_file_name,
+            Err(_) => return false,
+        };
+        let chain_head = AuditLogChainHeadStorage::<T>::get(&log_file_name);
+        let (head_hash, chain_length) = (chain_head.clone().get_hash(), chain_head.get_length());
+        if from > to || to >= chain_length {
+            return false;
+        }
+
+        let audit_logs = match (from..=to).map(|sequence| Self::chain_entry(&log_file_name, sequence)).collect::<Option<Vec<_>>>() {
+            Some(audit_logs) => audit_logs,
+            None => return false,
+        };
+        let previous_hash = match from.checked_sub(1) {
+            Some(previous_sequence) => match Self::chain_entry(&log_file_name, previous_sequence) {
+                Some(previous_audit_log) => previous_audit_log.chain_hash(&log_file_name),
+                // The audit log before has been pruned, only the first audit log still knows its chain hash
+                None => audit_logs[0].clone().get_previous_hash(),
+            },
+            None => Default::default(),
+        };
+        // The last audit log is either the head of the chain or linked from the one after it
+        let next_hash = if to + 1 == chain_length {
+            Some(head_hash)
+        } else {
+            Self::chain_entry(&log_file_name, to + 1).map(|next_audit_log| next_audit_log.get_previous_hash())
+        };
+
+        match crate::chain::verify_audit_log_chain::<T>(&log_file_name, from, previous_hash, &audit_logs) {
+            Ok(last_hash) => Some(last_hash) == next_hash,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the chain hash of the audit log at the index of the day, which is its leaf in the Merkle tree
+    /// of the day, along with its sibling path and the Merkle root of the day.
+    /// Returns None once an audit log of the day was removed, the leaves left no longer adding up to the root.
+    pub fn audit_log_entry_proof(log_file_name: Vec<u8>, log_day: AuditLogDay, entry_index: u32) -> Option<(T::Hash, Vec<T::Hash>, T::Hash)> {
+        let log_file_name: AuditLogFileName<T> = log_file_name.try_into().ok()?;
+
+        let entry_count = AuditLogEntryCountStorage::<T>::get(&log_file_name, log_day);
+        let leaves = (0..entry_count)
+            .map(|index| AuditLogEntryStorage::<T>::get((&log_file_name, log_day, index)).map(|audit_log| audit_log.chain_hash(&log_file_name)))
+            .collect::<Option<Vec<_>>>()?;
+        let siblings = crate::merkle::merkle_proof::<T::Hashing>(&leaves, entry_index)?;
+        let root = AuditLogMerkleStorage::<T>::get(&log_file_name, log_day).get_root();
+
+        Some((leaves[entry_index as usize], siblings, root))
+    }
+
+    /// Returns the schema the audit logs saved on the log file must follow, if any.
+    pub fn retrieve_active_log_schema(log_file_name: Vec<u8>) -> Option<AuditLogSchema<T>> {
+        let log_file_name: AuditLogFileName<T> = log_file_name.try_into().ok()?;
+        AuditLogSchemaStorage::<T>::get(log_file_name)
+    }
+
+    /// Returns the day of the moment, counted in days since the unix epoch.
+    pub fn day_of(moment: T::Moment) -> AuditLogDay {
+        (moment.saturated_into::<u64>() / MILLISECS_PER_DAY) as AuditLogDay
+    }
+
+    /// Returns the day of the block being built, which is the day audit logs saved in it are kept under.
+    pub fn current_day() -> AuditLogDay {
+        Self::day_of(<pallet_timestamp::Pallet<T>>::get())
+    }
+
+    /// Returns the deposit reserved for keeping the audit log on-chain, which grows with its encoded size.
+    pub fn audit_log_deposit(audit_log: &AuditLog<T>) -> BalanceOf<T> {
+        let byte_count: BalanceOf<T> = (audit_log.encoded_size() as u32).into();
+        T::DepositPerItem::get().saturating_add(T::DepositPerByte::get().saturating_mul(byte_count))
+    }
+
+    /// Returns the log files and days of the audit logs due to be pruned within the given number of days, along with
+    /// the day they are due on, the audit logs due the soonest first.
+    pub fn audit_logs_due_for_pruning(within_days: u32) -> Vec<(Vec<u8>, AuditLogDay, AuditLogDay)> {
+        let next_prune_day = match AuditLogNextPruneDayStorage::<T>::get() {
+            Some(next_prune_day) => next_prune_day,
+            None => return Vec::new(),
+        };
+        let last_prune_day = Self::current_day().saturating_add(within_days);
+
+        (next_prune_day..=last_prune_day)
+            .flat_map(|prune_day| {
+                AuditLogPruneQueueStorage::<T>::iter_key_prefix((prune_day,))
+                    .map(move |(log_file_name, log_day)| (log_file_name.into_inner(), log_day, prune_day))
+            })
+            .collect()
+    }
+
+    /// Returns the number of days the log file has audit logs for, which calls rescheduling them are charged for.
+    pub fn log_day_count(log_file_name: Vec<u8>) -> u32 {
+        let log_file_name: AuditLogFileName<T> = match log_file_name.try_into() {
+            Ok(log_file_name) => log_file_name,
+            Err(_) => return 0,
+        };
+        AuditLogEntryCountStorage::<T>::iter_key_prefix(&log_file_name).count() as u32
+    }
+
+    /// Returns the audit logs the reporter can still save on the log file in the next block and the bytes it can still
+    /// save in the rate limit window of the next block, under the limits of both the log file and the reporter. None
+    /// means unlimited.
+    pub fn remaining_rate_quota(log_file_name: Vec<u8>, reporter: T::AccountId) -> (Option<u32>, Option<u32>) {
+        let log_file_name: AuditLogFileName<T> = match log_file_name.try_into() {
+            Ok(log_file_name) => log_file_name,
+            Err(_) => return (None, None),
+        };
+        Self::rate_quota_at(&log_file_name, &reporter, <frame_system::Pallet<T>>::block_number().saturating_add(One::one()))
+    }
+
+    // Audit logs and bytes the reporter can still save on the log file at the block, none meaning unlimited
+    fn rate_quota_at(log_file_name: &AuditLogFileName<T>, reporter: &T::AccountId, now: T::BlockNumber) -> (Option<u32>, Option<u32>) {
+        let window_start = Self::rate_limit_window_start(now);
+
+        let file_quota = AuditLogFileRateLimitStorage::<T>::get(log_file_name).map(|rate_limit| {
+            rate_limit.remaining(&AuditLogFileRateUsageStorage::<T>::get(log_file_name), now, window_start)
+        });
+        let reporter_quota = AuditLogReporterRateLimitStorage::<T>::get(log_file_name, reporter).map(|rate_limit| {
+            rate_limit.remaining(&AuditLogReporterRateUsageStorage::<T>::get(log_file_name, reporter), now, window_start)
+        });
+
+        // The tightest of the limits applies
+        let tightest = |left: Option<u32>, right: Option<u32>| match (left, right) {
+            (Some(left), Some(right)) => Some(left.min(right)),
+            (left, right) => left.or(right),
+        };
+        let (file_entries, file_bytes) = file_quota.unwrap_or((None, None));
+        let (reporter_entries, reporter_bytes) = reporter_quota.unwrap_or((None, None));
+        (tightest(file_entries, reporter_entries), tightest(file_bytes, reporter_bytes))
+    }
+
+    /// Returns whether the fee the reporter paid for an audit log it saved on the log file is refunded, which it is for
+    /// an active registered reporter allowed to by the feeless reporter origin, on a log file where it has a rate limit
+    /// quota. Only audit logs that are saved have their fee refunded, those that fail pay it.
+    pub fn audit_log_fee(log_file_name: &AuditLogFileName<T>, reporter: &T::AccountId) -> Pays {
+        let feeless = AuditLogFeelessReporterStorage::<T>::get(reporter)
+            && Self::ensure_active_reporter(reporter).is_ok()
+            && (AuditLogFileRateLimitStorage::<T>::get(log_file_name).map_or(false, |rate_limit| rate_limit.is_limited())
+                || AuditLogReporterRateLimitStorage::<T>::get(log_file_name, reporter).map_or(false, |rate_limit| rate_limit.is_limited()));
+        if feeless { Pays::No } else { Pays::Yes }
+    }
+
+    /// Checks, only reading the storage, whether the audit log saved by the call of a feeless reporter fits in its rate
+    /// limit quota on the log file, and in the daily quota of its delegation when it saves as a delegate. Returns none
+    /// for the other calls, and for the audit logs that can't be built from the call, which are left to the dispatch.
+    pub fn check_feeless_audit_log(who: &T::AccountId, call: &Call<T>) -> Option<Result<(), Error<T>>> {
+        let (log_file_name, audit_log) = match call {
+            Call::save_audit_log { log_file_name, log_claimed_date, log_title, log_content, log_reporter_timestamp } => (
+                log_file_name,
+                Self::plain_content(log_content.clone()).and_then(|content| {
+                    Self::new_audit_log(who, log_claimed_date.clone(), log_title.clone(), content, log_reporter_timestamp.clone(), Default::default())
+                }),
+            ),
+            Call::save_structured_audit_log { log_file_name, log_claimed_date, log_title, log_content, log_reporter_timestamp, log_metadata } => (
+                log_file_name,
+                Self::plain_content(log_content.clone()).and_then(|content| {
+                    Self::new_audit_log(who, log_claimed_date.clone(), log_title.clone(), content, log_reporter_timestamp.clone(), log_metadata.clone())
+                }),
+            ),
+            Call::save_audit_log_hash { log_file_name, log_claimed_date, log_title, log_content_digest, log_content_length, log_content_locator, log_reporter_timestamp } => (
+                log_file_name,
+                Self::hashed_content(*log_content_digest, *log_content_length, log_content_locator.clone()).and_then(|content| {
+                    Self::new_audit_log(who, log_claimed_date.clone(), log_title.clone(), content, log_reporter_timestamp.clone(), Default::default())
+                }),
+            ),
+            Call::save_encrypted_audit_log {
+                log_file_name, log_claimed_date, log_title, log_ciphertext, log_nonce, log_ephemeral_public_key, log_wrapped_keys, log_reporter_timestamp,
+            } => (
+                log_file_name,
+                Self::encrypted_content(log_file_name, log_ciphertext.clone(), *log_nonce, *log_ephemeral_public_key, log_wrapped_keys.clone()).and_then(|(content, _)| {
+                    Self::new_audit_log(who, log_claimed_date.clone(), log_title.clone(), content, log_reporter_timestamp.clone(), Default::default())
+                }),
+            ),
+            _ => return None,
+        };
+        let log_file_name: AuditLogFileName<T> = log_file_name.clone().try_into().ok()?;
+        let audit_log = audit_log.ok()?;
+        if Self::audit_log_fee(&log_file_name, who) == Pays::Yes {
+            return None
+        }
+
+        // Checked at the block being built, the block transactions are validated for as well
+        let now = <frame_system::Pallet<T>>::block_number();
+        let (entries, bytes) = Self::rate_quota_at(&log_file_name, who, now);
+        if entries == Some(0) || bytes.map_or(false, |bytes| bytes < Self::rate_limited_size(&audit_log)) {
+            return Some(Err(Error::<T>::RateLimitExceeded))
+        }
+
+        // Delegates have their audit logs counted against the daily quota of their delegation
+        let roles = AuditLogRoleStorage::<T>::get(&log_file_name);
+        if Self::role_of(&roles, who).map_or(false, |role| role.can_write()) {
+            return Some(Ok(()))
+        }
+        Some(match AuditLogDelegationStorage::<T>::get(&log_file_name, who) {
+            Some(delegation) => delegation.check_entry(now, Self::current_day()),
+            None => Err(Error::<T>::AuditLogIdentifierCannotBeUsed),
+        })
+    }
+
+    /// Returns how query tools show the reporter, such as `payments-api (prod)`, if it is registered.
+    pub fn reporter_label(reporter: T::AccountId) -> Option<Vec<u8>> {
+        AuditLogReporterStorage::<T>::get(reporter).map(|reporter| reporter.label())
+    }
+
+    /// Returns the accounts that own, meaning administer, the log file.
+    pub fn retrieve_audit_log_owner(log_file_name: AuditLogFileName<T>) -> Vec<T::AccountId> {
+        AuditLogRoleStorage::<T>::get(log_file_name)
+            .into_inner()
+            .into_iter()
+            .filter(|(_, role)| role.can_manage())
+            .map(|(member, _)| member)
+            .collect()
+    }
+
+	fn add_claimer_as_log_owner(log_file_name: &AuditLogFileName<T>, claimer: T::AccountId) -> DispatchResult {
+		// Add the claimer as an owner of the audit log
+        let mut audit_log_roles_collection = AuditLogRoleStorage::<T>::get(log_file_name).into_inner();
+        audit_log_roles_collection.retain(|(member, _)| member != &claimer);
+        audit_log_roles_collection.push((claimer, AuditLogRole::Admin));
+        Self::store_roles(log_file_name, audit_log_roles_collection)?;
+
+        Self::close_log_for_claim(log_file_name);
+
+        Ok(())
+	}
+
+    fn save_log(sender: T::AccountId, log_file_name: Vec<u8>, log_claimed_date: Option<Vec<u8>>, log_title: Vec<u8>, content: AuditLogContent<T>, log_reporter_timestamp: Vec<u8>, log_metadata: AuditLogMetadata) -> Result<(AuditLogFileName<T>, AuditLogDay, u32), DispatchError> {
+        let log_file_name = Self::bounded::<T::MaxFileNameLen>(log_file_name, Error::<T>::AuditLogFileNameTooLong)?;
+        let log_day = Self::current_day();
+        let audit_log = Self::new_audit_log(&sender, log_claimed_date, log_title, content, log_reporter_timestamp, log_metadata)?;
+
+        Self::ensure_can_write(&log_file_name, &sender)?;
+        if let Some(schema) = AuditLogSchemaStorage::<T>::get(&log_file_name) {
+            schema.check(&audit_log)?;
+        }
+        Self::record_rate_usage(&log_file_name, &sender, &audit_log)?;
+        Self::reserve_deposit(&log_file_name, log_day, &sender, &audit_log)?;
+        let stored_event = Self::stored_event(&log_file_name, log_day, &audit_log);
+        let entry_index = Self::append_audit_log(&log_file_name, log_day, audit_log)?;
+
+        // Emit the event that audit log has been added in chain
+        Self::deposit_event(stored_event);
+
+        Ok((log_file_name, log_day, entry_index))
+    }
+
+    fn plain_content(log_content: Vec<u8>) -> Result<AuditLogContent<T>, DispatchError> {
+        Ok(AuditLogContent::Plain(Self::bounded(log_content, Error::<T>::AuditLogContentTooLong)?))
+    }
+
+    fn hashed_content(log_content_digest: T::Hash, log_content_length: u32, log_content_locator: Option<Vec<u8>>) -> Result<AuditLogContent<T>, DispatchError> {
+        let locator = match log_content_locator {
+            Some(locator) => Some(Self::bounded(locator, Error::<T>::AuditLogLocatorTooLong)?),
+            None => None,
+        };
+        Ok(AuditLogContent::Hashed { digest: log_content_digest, length: log_content_length, locator })
+    }
+
+    // Seals the content in the key epoch the log file is in, returned along with the content
+    fn encrypted_content(
+        log_file_name: &[u8],
+        log_ciphertext: Vec<u8>,
+        log_nonce: AuditLogNonce,
+        log_ephemeral_public_key: AuditLogEncryptionKey,
+        log_wrapped_keys: Vec<(T::AccountId, AuditLogWrappedKey)>,
+    ) -> Result<(AuditLogContent<T>, u32), DispatchError> {
+        let ciphertext = Self::bounded(log_ciphertext, Error::<T>::AuditLogContentTooLong)?;
+        let key_epoch = AuditLogKeyEpochStorage::<T>::get(Self::bounded::<T::MaxFileNameLen>(log_file_name.to_vec(), Error::<T>::AuditLogFileNameTooLong)?);
+        let wrapped_keys = Self::wrapped_keys(key_epoch.as_ref(), log_wrapped_keys)?;
+        let epoch = key_epoch.map_or(0, |key_epoch| key_epoch.get_epoch());
+        Ok((AuditLogContent::Encrypted(AuditLogEncryptedContent::new(ciphertext, log_nonce, log_ephemeral_public_key, wrapped_keys, epoch)), epoch))
+    }
+
+    fn wrapped_keys(key_epoch: Option<&AuditLogKeyEpoch<T>>, wrapped_keys: Vec<(T::AccountId, AuditLogWrappedKey)>) -> Result<AuditLogWrappedKeys<T>, Error<T>> {
+        let wrapped_keys: AuditLogWrappedKeys<T> = wrapped_keys.try_into().map_err(|_| Error::<T>::TooManyReaders)?;
+        frame_support::ensure!(!wrapped_keys.is_empty(), Error::<T>::NoReaders);
+        for (index, (reader, _)) in wrapped_keys.iter().enumerate() {
+            frame_support::ensure!(!wrapped_keys[..index].iter().any(|(other_reader, _)| other_reader == reader), Error::<T>::DuplicateReader);
+        }
+
+        // Within a key epoch the readers are those of the epoch, sealed for with the encryption keys they had when it started
+        match key_epoch {
+            Some(key_epoch) => frame_support::ensure!(key_epoch.is_sealed_for_readers(&wrapped_keys), Error::<T>::ReadersNotInKeyEpoch),
+            None => {
+                for (reader, _) in wrapped_keys.iter() {
+                    frame_support::ensure!(AuditLogEncryptionKeyStorage::<T>::contains_key(reader), Error::<T>::ReaderHasNoEncryptionKey);
+                }
+            },
+        }
+        Ok(wrapped_keys)
+    }
+
+    fn new_audit_log(sender: &T::AccountId, log_claimed_date: Option<Vec<u8>>, log_title: Vec<u8>, content: AuditLogContent<T>, log_reporter_timestamp: Vec<u8>, log_metadata: AuditLogMetadata) -> Result<AuditLog<T>, DispatchError> {
+        let tags = log_metadata.tags
+            .into_iter()
+            .map(|tag| Self::bounded(tag, Error::<T>::AuditLogTagTooLong))
+            .collect::<Result<Vec<_>, _>>()?;
+        let attributes = log_metadata.attributes
+            .into_iter()
+            .map(|(key, value)| Ok((
+                Self::bounded(key, Error::<T>::AuditLogAttributeKeyTooLong)?,
+                Self::bounded(value, Error::<T>::AuditLogAttributeValueTooLong)?,
+            )))
+            .collect::<Result<Vec<_>, Error<T>>>()?;
+        let claimed_date = match log_claimed_date {
+            Some(claimed_date) => Some(Self::bounded(claimed_date, Error::<T>::AuditLogDateTooLong)?),
+            None => None,
+        };
+
+        // Linked into the chain of the log file once appended
+        Ok(AuditLog::new(
+            Self::bounded(log_title, Error::<T>::AuditLogTitleTooLong)?,
+            content,
+            Self::bounded(log_reporter_timestamp, Error::<T>::AuditLogReporterTimestampTooLong)?,
+            <pallet_timestamp::Pallet<T>>::get(),
+            sender.clone(),
+            0,
+            Default::default(),
+        ).with_metadata(
+            log_metadata.severity,
+            tags.try_into().map_err(|_| Error::<T>::TooManyAuditLogTags)?,
+            attributes.try_into().map_err(|_| Error::<T>::TooManyAuditLogAttributes)?,
+        ).with_claimed_date(claimed_date))
+    }
+
+    fn stored_event(log_file_name: &AuditLogFileName<T>, log_day: AuditLogDay, audit_log: &AuditLog<T>) -> Event<T> {
+        Event::AuditLogInformationStored(
+            log_file_name.clone(),
+            log_day,
+            audit_log.clone().get_reporter(),
+            audit_log.clone().get_severity(),
+            audit_log.clone().get_tags(),
+            audit_log.clone().get_attributes(),
+        )
+    }
+
+    // Returns whether the sender writes as a delegate, every audit log it saves then counting towards its daily quota
+    fn ensure_can_write(log_file_name: &AuditLogFileName<T>, sender: &T::AccountId) -> Result<bool, DispatchError> {
+        let log_roles = AuditLogRoleStorage::<T>::try_get(log_file_name);
+        let mut as_delegate = false;
+        match log_roles {
+            // log file name is already owned, meaning it is existing
+            Ok(roles) => {
+                // check if the transaction sender is allowed to write on the log file name, by its role or as a delegate
+                if !Self::role_of(&roles, sender).map_or(false, |role| role.can_write()) {
+                    Self::record_delegate_entry(log_file_name, sender)?;
+                    as_delegate = true;
+                }
+            }
+            Err(_error) => {
+                // No owner for this log name yet, therefore it is not existing and is available once what remains of
+                // a log file deleted under the name has been removed
+                frame_support::ensure!(!AuditLogDeletedFileStorage::<T>::contains_key(log_file_name), Error::<T>::LogFileBeingDeleted);
+                let mut new_audit_log_roles_collection = Vec::new();
+                new_audit_log_roles_collection.push((sender.clone(), AuditLogRole::Admin));
+                Self::store_roles(log_file_name, new_audit_log_roles_collection)?;
+                Self::deposit_event(Event::AuditLogRoleGranted(log_file_name.clone(), sender.clone(), AuditLogRole::Admin));
+            }
+        }
+
+        if AuditLogRegisteredReportersOnlyStorage::<T>::get(log_file_name) {
+            Self::ensure_active_reporter(sender)?;
+        }
+
+        Ok(as_delegate)
+    }
+
+    fn record_delegate_entry(log_file_name: &AuditLogFileName<T>, delegate: &T::AccountId) -> DispatchResult {
+        AuditLogDelegationStorage::<T>::try_mutate(log_file_name, delegate, |delegation| -> DispatchResult {
+            let delegation = delegation.as_mut().ok_or(Error::<T>::AuditLogIdentifierCannotBeUsed)?;
+            delegation.record_entry(<frame_system::Pallet<T>>::block_number(), Self::current_day())?;
+            Ok(())
+        })
+    }
+
+    // Bytes an audit log counts for against the rate limits, the same before and after it is linked into its chain
+    fn rate_limited_size(audit_log: &AuditLog<T>) -> u32 {
+        audit_log.encoded_size() as u32
+    }
+
+    fn record_rate_usage(log_file_name: &AuditLogFileName<T>, reporter: &T::AccountId, audit_log: &AuditLog<T>) -> DispatchResult {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let window_start = Self::rate_limit_window_start(now);
+        let bytes = Self::rate_limited_size(audit_log);
+
+        // Both rate limits are checked before either usage is written
+        let file_usage = match AuditLogFileRateLimitStorage::<T>::get(log_file_name) {
+            Some(rate_limit) => {
+                let mut usage = AuditLogFileRateUsageStorage::<T>::get(log_file_name);
+                rate_limit.record(&mut usage, now, window_start, bytes)?;
+                Some(usage)
+            },
+            None => None,
+        };
+        let reporter_usage = match AuditLogReporterRateLimitStorage::<T>::get(log_file_name, reporter) {
+            Some(rate_limit) => {
+                let mut usage = AuditLogReporterRateUsageStorage::<T>::get(log_file_name, reporter);
+                rate_limit.record(&mut usage, now, window_start, bytes)?;
+                Some(usage)
+            },
+            None => None,
+        };
+
+        if let Some(usage) = file_usage {
+            AuditLogFileRateUsageStorage::<T>::insert(log_file_name, usage);
+        }
+        if let Some(usage) = reporter_usage {
+            AuditLogReporterRateUsageStorage::<T>::insert(log_file_name, reporter, usage);
+        }
+
+        Ok(())
+    }
+
+    // First block of the rate limit window the block is in
+    fn rate_limit_window_start(now: T::BlockNumber) -> T::BlockNumber {
+        let window = T::RateLimitWindow::get().max(One::one());
+        now - now % window
+    }
+
+    fn ensure_active_reporter(account: &T::AccountId) -> DispatchResult {
+        let reporter = AuditLogReporterStorage::<T>::get(account).ok_or(Error::<T>::ReporterNotRegistered)?;
+        frame_support::ensure!(reporter.is_active(), Error::<T>::ReporterIsRetired);
+        Ok(())
+    }
+
+    fn new_reporter(account: &T::AccountId, system_name: Vec<u8>, environment: Vec<u8>, software_version: Vec<u8>, contact_hash: T::Hash) -> Result<AuditLogReporter<T>, Error<T>> {
+        Ok(AuditLogReporter::new(
+            account.clone(),
+            Self::bounded(system_name, Error::<T>::ReporterFieldTooLong)?,
+            Self::bounded(environment, Error::<T>::ReporterFieldTooLong)?,
+            Self::bounded(software_version, Error::<T>::ReporterFieldTooLong)?,
+            contact_hash,
+        ))
+    }
+
+    // Returns the index of the audit log within the day
+    fn append_audit_log(log_file_name: &AuditLogFileName<T>, log_day: AuditLogDay, mut audit_log: AuditLog<T>) -> Result<u32, DispatchError> {
+        // Only the entry and the counter of the day are written, whatever the number of audit logs saved before
+        let entry_count = AuditLogEntryCountStorage::<T>::get(log_file_name, log_day);
+        frame_support::ensure!(entry_count < T::MaxEntriesPerDay::get(), Error::<T>::TooManyAuditLogEntriesForDate);
+
+        // Link the audit log to the latest one of the log file, whatever its day
+        let chain_head = AuditLogChainHeadStorage::<T>::get(log_file_name);
+        let sequence = chain_head.clone().get_length();
+        audit_log.link(sequence, chain_head.get_hash());
+        let chain_hash = audit_log.chain_hash(log_file_name);
+        let new_chain_head = AuditLogChainHead::new(chain_hash, sequence + 1);
+
+        AuditLogEntryStorage::<T>::insert((log_file_name, log_day, entry_count), audit_log);
+        AuditLogEntryCountStorage::<T>::insert(log_file_name, log_day, entry_count + 1);
+        AuditLogChainLocationStorage::<T>::insert(log_file_name, sequence, (log_day, entry_count));
+        AuditLogChainHeadStorage::<T>::insert(log_file_name, new_chain_head);
+        AuditLogMerkleStorage::<T>::mutate(log_file_name, log_day, |merkle_tree| merkle_tree.insert(entry_count, chain_hash));
+
+        // The audit logs of a day are pruned together, once the last of them is over the retention period
+        if entry_count == 0 {
+            if let Some(retention_days) = AuditLogRetentionStorage::<T>::get(log_file_name) {
+                Self::schedule_pruning(log_file_name, log_day, retention_days);
+            }
+        }
+
+        Ok(entry_count)
+    }
+
+    fn reserve_deposit(log_file_name: &AuditLogFileName<T>, log_day: AuditLogDay, depositor: &T::AccountId, audit_log: &AuditLog<T>) -> DispatchResult {
+        let deposit = Self::audit_log_deposit(audit_log);
+        T::Currency::reserve(depositor, deposit).map_err(|_| Error::<T>::InsufficientBalanceForDeposit)?;
+        AuditLogDepositStorage::<T>::mutate((log_file_name, log_day, depositor), |reserved| *reserved = reserved.saturating_add(deposit));
+
+        // Emit the event that the deposit for the audit log has been reserved
+        Self::deposit_event(Event::AuditLogDepositReserved(log_file_name.clone(), depositor.clone(), deposit));
+
+        Ok(())
+    }
+
+    fn release_deposits(log_file_name: &AuditLogFileName<T>, deposits: Vec<(T::AccountId, BalanceOf<T>)>) {
+        // Accounts that saved audit logs on several days get their deposits back at once
+        let mut deposits_by_depositor: Vec<(T::AccountId, BalanceOf<T>)> = Vec::new();
+        for (depositor, deposit) in deposits {
+            match deposits_by_depositor.iter_mut().find(|(known_depositor, _)| known_depositor == &depositor) {
+                Some((_, total)) => *total = total.saturating_add(deposit),
+                None => deposits_by_depositor.push((depositor, deposit)),
+            }
+        }
+
+        for (depositor, deposit) in deposits_by_depositor {
+            T::Currency::unreserve(&depositor, deposit);
+
+            // Emit the event that the deposit has been given back
+            Self::deposit_event(Event::AuditLogDepositUnreserved(log_file_name.clone(), depositor, deposit));
+        }
+    }
+
+    // Removes what is kept once per log file, what is kept per audit log, day or account being left to
+    // `clear_deleted_log_files`
+    fn remove_log_file(log_file_name: &AuditLogFileName<T>) {
+        AuditLogChainHeadStorage::<T>::remove(log_file_name);
+        AuditLogSchemaStorage::<T>::remove(log_file_name);
+        AuditLogRoleStorage::<T>::remove(log_file_name);
+        AuditLogRetentionStorage::<T>::remove(log_file_name);
+        AuditLogKeyEpochStorage::<T>::remove(log_file_name);
+        AuditLogRegisteredReportersOnlyStorage::<T>::remove(log_file_name);
+        AuditLogFileRateLimitStorage::<T>::remove(log_file_name);
+        AuditLogFileRateUsageStorage::<T>::remove(log_file_name);
+        Self::close_log_for_claim(log_file_name);
+        AuditLogDeletedFileStorage::<T>::insert(log_file_name, ());
+    }
+
+    fn clear_deleted_log_files(remaining_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        // Finding a deleted log file
+        let mut used_weight = db_weight.reads(1);
+        if used_weight > remaining_weight {
+            return 0
+        }
+        // Reading and removing a key, along with the account a deposit is given back to
+        let key_weight = db_weight.reads_writes(2, 2);
+        while let Some(log_file_name) = AuditLogDeletedFileStorage::<T>::iter_keys().next() {
+            let limit = (remaining_weight.saturating_sub(used_weight) / key_weight.max(1)).min(u32::MAX as Weight) as u32;
+            if limit == 0 {
+                break
+            }
+            let (removed, cleared) = Self::clear_deleted_log_file(&log_file_name, limit);
+            used_weight = used_weight.saturating_add(key_weight.saturating_mul(removed as Weight));
+            if !cleared {
+                break
+            }
+
+            AuditLogDeletedFileStorage::<T>::remove(&log_file_name);
+            used_weight = used_weight.saturating_add(db_weight.reads_writes(1, 1));
+
+            // Emit the event that nothing remains of the log file
+            Self::deposit_event(Event::AuditLogFileCleared(log_file_name));
+        }
+        used_weight
+    }
+
+    // Removes at most `limit` keys kept for the deleted log file, returning how many were removed and whether none
+    // are left
+    fn clear_deleted_log_file(log_file_name: &AuditLogFileName<T>, limit: u32) -> (u32, bool) {
+        let deposits = AuditLogDepositStorage::<T>::drain_prefix((log_file_name.clone(),))
+            .take(limit as usize)
+            .map(|((_, depositor), deposit)| (depositor, deposit))
+            .collect::<Vec<_>>();
+        let mut removed = deposits.len() as u32;
+        Self::release_deposits(log_file_name, deposits);
+
+        if removed == limit
+            || Self::drain_page(AuditLogEntryStorage::<T>::drain_prefix((log_file_name.clone(),)), &mut removed, limit)
+            || Self::drain_page(AuditLogEntryCountStorage::<T>::drain_prefix(log_file_name), &mut removed, limit)
+            || Self::drain_page(AuditLogMerkleStorage::<T>::drain_prefix(log_file_name), &mut removed, limit)
+            || Self::drain_page(AuditLogChainLocationStorage::<T>::drain_prefix(log_file_name), &mut removed, limit)
+            || Self::drain_page(AuditLogDelegationStorage::<T>::drain_prefix(log_file_name), &mut removed, limit)
+            || Self::drain_page(AuditLogReporterRateLimitStorage::<T>::drain_prefix(log_file_name), &mut removed, limit)
+            || Self::drain_page(AuditLogReporterRateUsageStorage::<T>::drain_prefix(log_file_name), &mut removed, limit)
+            || Self::drain_page(
+                AuditLogPruneDayStorage::<T>::drain_prefix(log_file_name)
+                    .map(|(log_day, prune_day)| AuditLogPruneQueueStorage::<T>::remove((prune_day, log_file_name, log_day))),
+                &mut removed,
+                limit,
+            )
+        {
+            return (removed, false)
+        }
+        (removed, true)
+    }
+
+    // Removes the keys of the drained storage until the limit is reached, returning whether it was
+    fn drain_page<I: Iterator>(drained: I, removed: &mut u32, limit: u32) -> bool {
+        *removed += drained.take(limit.saturating_sub(*removed) as usize).count() as u32;
+        *removed >= limit
+    }
+
+    fn schedule_pruning(log_file_name: &AuditLogFileName<T>, log_day: AuditLogDay, retention_days: u32) {
+        // Days before the next one to prune on have been gone through already, audit logs due on them are due now
+        let today = Self::current_day();
+        let next_prune_day = match AuditLogNextPruneDayStorage::<T>::get() {
+            Some(next_prune_day) if next_prune_day <= today => next_prune_day,
+            _ => {
+                AuditLogNextPruneDayStorage::<T>::put(today);
+                today
+            }
+        };
+        let prune_day = log_day.saturating_add(retention_days).max(next_prune_day);
+
+        AuditLogPruneDayStorage::<T>::insert(log_file_name, log_day, prune_day);
+        AuditLogPruneQueueStorage::<T>::insert((prune_day, log_file_name, log_day), ());
+    }
+
+    // Returns the days the log file has audit logs for, failing when there are more of them than the caller was
+    // charged for
+    fn log_days_within(log_file_name: &AuditLogFileName<T>, log_day_count: u32) -> Result<Vec<AuditLogDay>, DispatchError> {
+        let log_days = AuditLogEntryCountStorage::<T>::iter_key_prefix(log_file_name)
+            .take((log_day_count as usize).saturating_add(1))
+            .collect::<Vec<_>>();
+        frame_support::ensure!(log_days.len() <= log_day_count as usize, Error::<T>::LogDayCountTooLow);
+        Ok(log_days)
+    }
+
+    fn reschedule_pruning(log_file_name: &AuditLogFileName<T>, log_days: Vec<AuditLogDay>) {
+        let retention_days = AuditLogRetentionStorage::<T>::get(log_file_name);
+        for log_day in log_days {
+            Self::unschedule_pruning(log_file_name, log_day);
+            if let Some(retention_days) = retention_days {
+                Self::schedule_pruning(log_file_name, log_day, retention_days);
+            }
+        }
+    }
+
+    fn ensure_not_under_legal_hold(log_file_name: &AuditLogFileName<T>) -> DispatchResult {
+        frame_support::ensure!(!AuditLogLegalHoldStorage::<T>::contains_key(log_file_name), Error::<T>::LogFileUnderLegalHold);
+        Ok(())
+    }
+
+    fn unschedule_pruning(log_file_name: &AuditLogFileName<T>, log_day: AuditLogDay) {
+        if let Some(prune_day) = AuditLogPruneDayStorage::<T>::take(log_file_name, log_day) {
+            AuditLogPruneQueueStorage::<T>::remove((prune_day, log_file_name, log_day));
+        }
+    }
+
+    fn prune_expired_audit_logs(remaining_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        // Reading and writing the next day to prune on, as well as reading the timestamp
+        let mut used_weight = db_weight.reads_writes(2, 1);
+        if used_weight > remaining_weight {
+            return 0
+        }
+        let mut prune_day = match AuditLogNextPruneDayStorage::<T>::get() {
+            Some(prune_day) => prune_day,
+            None => return db_weight.reads(1),
+        };
+
+        let today = Self::current_day();
+        // Finding what is due on a day and whether it is under legal hold, then removing what remains of the audit logs of the day
+        let log_day_weight = db_weight.reads_writes(5, 5);
+        // Reading and removing an audit log, then removing its location in the chain of the log file
+        let entry_weight = db_weight.reads_writes(1, 2);
+        while prune_day <= today {
+            let (log_file_name, log_day) = match AuditLogPruneQueueStorage::<T>::iter_key_prefix((prune_day,)).next() {
+                Some(due) => due,
+                None => {
+                    prune_day += 1;
+                    used_weight = used_weight.saturating_add(db_weight.reads(1));
+                    if used_weight > remaining_weight {
+                        break
+                    }
+                    continue
+                }
+            };
+
+            // Audit logs under legal hold are taken off the schedule, releasing the hold schedules them again
+            if AuditLogLegalHoldStorage::<T>::contains_key(&log_file_name) {
+                Self::unschedule_pruning(&log_file_name, log_day);
+                used_weight = used_weight.saturating_add(db_weight.reads_writes(3, 2));
+                if used_weight > remaining_weight {
+                    break
+                }
+                continue
+            }
+
+            // At least one audit log is pruned, the others wait for the next blocks if there is no weight left for them
+            let available_weight = remaining_weight.saturating_sub(used_weight).saturating_sub(log_day_weight);
+            if used_weight.saturating_add(log_day_weight).saturating_add(entry_weight) > remaining_weight {
+                break
+            }
+            let max_entries = (available_weight / entry_weight.max(1)).min(u32::MAX as Weight) as usize;
+            let mut pruned: u32 = 0;
+            for (_, audit_log) in AuditLogEntryStorage::<T>::drain_prefix((log_file_name.clone(), log_day)).take(max_entries) {
+                AuditLogChainLocationStorage::<T>::remove(&log_file_name, audit_log.get_sequence());
+                pruned += 1;
+            }
+            used_weight = used_weight.saturating_add(log_day_weight).saturating_add(entry_weight.saturating_mul(pruned as Weight));
+
+            if AuditLogEntryStorage::<T>::iter_key_prefix((log_file_name.clone(), log_day)).next().is_none() {
+                AuditLogEntryCountStorage::<T>::remove(&log_file_name, log_day);
+                AuditLogMerkleStorage::<T>::remove(&log_file_name, log_day);
+                Self::unschedule_pruning(&log_file_name, log_day);
+                let deposits = AuditLogDepositStorage::<T>::drain_prefix((log_file_name.clone(), log_day)).collect::<Vec<_>>();
+                used_weight = used_weight.saturating_add(db_weight.reads_writes(2, 2).saturating_mul(deposits.len() as Weight));
+                Self::release_deposits(&log_file_name, deposits);
+            }
+
+            // Emit the event that audit logs of the day have been pruned
+            Self::deposit_event(Event::AuditLogsPruned(log_file_name, log_day, pruned));
+        }
+
+        AuditLogNextPruneDayStorage::<T>::put(prune_day);
+        used_weight
+    }
+
+    fn chain_entry(log_file_name: &AuditLogFileName<T>, sequence: u64) -> Option<AuditLog<T>> {
+        let (log_day, entry_index) = AuditLogChainLocationStorage::<T>::get(log_file_name, sequence)?;
+        AuditLogEntryStorage::<T>::get((log_file_name, log_day, entry_index))
+    }
+
+    fn open_log_for_claim(log_file_name: &AuditLogFileName<T>, claimer_pubkey: [u8; 32]) -> DispatchResult {
+        // Convert u32 raw byte to AccountId of the would be assigned claimer
+        let claimer_account_id = T::AccountId::decode(&mut &claimer_pubkey[..]).unwrap_or_default();
+
+        let expires_at = <frame_system::Pallet<T>>::block_number() + T::ClaimWindow::get();
+
+        // Schedule the claim to be closed once the claim window is over
+        AuditLogClaimExpiryStorage::<T>::try_mutate(expires_at, |expiring_log_file_names| {
+            expiring_log_file_names.try_push(log_file_name.clone())
+        }).map_err(|_| Error::<T>::TooManyClaimsExpiringInBlock)?;
+
+        let audit_log_open_for_claim = AuditLogOpenForClaim::new(
+            log_file_name.clone(),
+            claimer_account_id,
+            <pallet_timestamp::Pallet<T>>::get(),
+            expires_at,
+        );
+
+        // Open the log for ownership claim
+        AuditLogOpenForClaimStorage::<T>::insert(log_file_name, audit_log_open_for_claim);
+
+        Ok(())
+    }
+
+    fn close_log_for_claim(log_file_name: &AuditLogFileName<T>) {
+        // Remove the audit log as open for claiming so that is not open anymore (delete from the AuditLogOpenForClaimStorage)
+        if let Ok(audit_log_open_for_claim) = AuditLogOpenForClaimStorage::<T>::try_get(log_file_name) {
+            AuditLogOpenForClaimStorage::<T>::remove(log_file_name);
+
+            // Free its place among the claims expiring in the same block
+            AuditLogClaimExpiryStorage::<T>::mutate(audit_log_open_for_claim.get_expires_at(), |expiring_log_file_names| {
+                expiring_log_file_names.retain(|expiring_log_file_name| expiring_log_file_name != log_file_name)
+            });
+        }
+    }
+
+    fn ensure_can_change_log_claim(log_file_name: &AuditLogFileName<T>, sender: &T::AccountId) -> DispatchResult {
+        // Only the current owners of an existing log can change its pending claim
+        let roles = AuditLogRoleStorage::<T>::try_get(log_file_name).map_err(|_| Error::<T>::AuditLogCantBeFound)?;
+        frame_support::ensure!(Self::role_of(&roles, sender).map_or(false, |role| role.can_manage()), Error::<T>::NoRightsToChangeAuditLogClaim);
+        frame_support::ensure!(AuditLogOpenForClaimStorage::<T>::contains_key(log_file_name), Error::<T>::AuditLogNotOpenedForClaiming);
+
+        Ok(())
+    }
+
+    fn expire_ownership_claims(now: T::BlockNumber) -> Weight {
+        let expiring_log_file_names = AuditLogClaimExpiryStorage::<T>::take(now);
+        let mut expired_claims: u64 = 0;
+
+        for log_file_name in expiring_log_file_names.iter() {
+            if let Ok(audit_log_for_claim) = AuditLogOpenForClaimStorage::<T>::try_get(log_file_name) {
+                // The log may have been claimed and opened again since, with a later expiry
+                if audit_log_for_claim.clone().get_expires_at() <= now {
+                    AuditLogOpenForClaimStorage::<T>::remove(log_file_name);
+                    expired_claims += 1;
+
+                    // Emit the event that the claim has expired without being claimed
+                    Self::deposit_event(Event::AuditLogClaimExpired(log_file_name.clone(), audit_log_for_claim.get_assigned_claimer()));
+                }
+            }
+        }
+
+        T::DbWeight::get().reads_writes(1 + expiring_log_file_names.len() as u64, 1 + expired_claims)
+    }
+
+    fn bounded<S: Get<u32>>(value: Vec<u8>, error: Error<T>) -> Result<BoundedVec<u8, S>, Error<T>> {
+        value.try_into().map_err(|_| error)
+    }
+
+    fn role_of(roles: &[(T::AccountId, AuditLogRole)], account: &T::AccountId) -> Option<AuditLogRole> {
+        roles.iter().find(|(member, _)| member == account).map(|(_, role)| *role)
+    }
+
+    fn store_roles(log_file_name: &AuditLogFileName<T>, roles: Vec<(T::AccountId, AuditLogRole)>) -> DispatchResult {
+        let roles: AuditLogRoles<T> = roles.try_into().map_err(|_| Error::<T>::TooManyAuditLogOwners)?;
+        AuditLogRoleStorage::<T>::insert(log_file_name, roles);
+
+        Ok(())
+    }
+
+    fn ensure_log_admin(log_file_name: &AuditLogFileName<T>, sender: &T::AccountId) -> Result<Vec<(T::AccountId, AuditLogRole)>, DispatchError> {
+        // Only the admins of an existing log can manage its members
+        let roles = AuditLogRoleStorage::<T>::try_get(log_file_name).map_err(|_| Error::<T>::AuditLogCantBeFound)?;
+        frame_support::ensure!(Self::role_of(&roles, sender).map_or(false, |role| role.can_manage()), Error::<T>::NoRightsToManageAuditLogRoles);
+
+        Ok(roles.into_inner())
+    }
+
+    fn ensure_has_admin(roles: &[(T::AccountId, AuditLogRole)]) -> DispatchResult {
+        // A log file without admins could not be managed anymore
+        frame_support::ensure!(roles.iter().any(|(_, role)| role.can_manage()), Error::<T>::AuditLogMustKeepAnAdmin);
+
+        Ok(())
+    }
+
+    fn remove_log_owner(log_file_name: &AuditLogFileName<T>, mut roles: Vec<(T::AccountId, AuditLogRole)>, owner: &T::AccountId) -> DispatchResult {
+        frame_support::ensure!(Self::role_of(&roles, owner).map_or(false, |role| role.can_manage()), Error::<T>::AccountIsNotAuditLogOwner);
+
+        // The log file cannot be left without an owner
+        roles.retain(|(member, _)| member != owner);
+        Self::ensure_has_admin(&roles)?;
+        Self::store_roles(log_file_name, roles)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+//! Merkle trees over the audit logs of a log file for a day.
+//!
+//! The tree has a fixed depth covering every index an audit log can have within a day, the
+//! leaves are the chain hashes of the audit logs in the order they were saved and the missing
+//! leaves are default hashes. Only the rightmost branch of the tree is kept on-chain, which is
+//! enough to add a leaf and compute the root without reading the audit logs.
+
+use frame_support::sp_runtime::traits::Hash;
+use scale_info::prelude::vec::Vec;
+
+/// Depth of the tree, one level per bit of the index of an audit log within a day.
+pub const AUDIT_LOG_MERKLE_DEPTH: usize = 32;
+
+/// Hash of an inner node of the tree.
+pub fn merkle_node<H: Hash>(left: &H::Output, right: &H::Output) -> H::Output {
+	let mut node = Vec::with_capacity(left.as_ref().len() + right.as_ref().len());
+	node.extend_from_slice(left.as_ref());
+	node.extend_from_slice(right.as_ref());
+	H::hash(&node)
+}
+
+// Root of an empty subtree at every level, starting with the missing leaf
+fn zero_hashes<H: Hash>() -> Vec<H::Output> {
+	let mut zero_hashes = Vec::with_capacity(AUDIT_LOG_MERKLE_DEPTH);
+	zero_hashes.push(H::Output::default());
+	for level in 1..AUDIT_LOG_MERKLE_DEPTH {
+		let zero_hash = merkle_node::<H>(&zero_hashes[level - 1], &zero_hashes[level - 1]);
+		zero_hashes.push(zero_hash);
+	}
+	zero_hashes
+}
+
+/// Adds the leaf at the index to the rightmost branch of a tree holding `leaf_index` leaves.
+pub fn merkle_insert<H: Hash>(branch: &mut [H::Output; AUDIT_LOG_MERKLE_DEPTH], leaf_index: u32, leaf: H::Output) {
+	let mut node = leaf;
+	let mut size = leaf_index as u64 + 1;
+	for level in 0..AUDIT_LOG_MERKLE_DEPTH {
+		if size & 1 == 1 {
+			branch[level] = node;
+			return
+		}
+		node = merkle_node::<H>(&branch[level], &node);
+		size >>= 1;
+	}
+}
+
+/// Root of a tree holding `leaf_count` leaves, from its rightmost branch.
+pub fn merkle_root<H: Hash>(branch: &[H::Output; AUDIT_LOG_MERKLE_DEPTH], leaf_count: u32) -> H::Output {
+	let zero_hashes = zero_hashes::<H>();
+	let mut node = H::Output::default();
+	let mut size = leaf_count as u64;
+	for level in 0..AUDIT_LOG_MERKLE_DEPTH {
+		node = if size & 1 == 1 {
+			merkle_node::<H>(&branch[level], &node)
+		} else {
+			merkle_node::<H>(&node, &zero_hashes[level])
+		};
+		size >>= 1;
+	}
+	node
+}
+
+/// Sibling path of the leaf at the index, from the leaf up to the root, or `None` when there
+/// is no such leaf.
+pub fn merkle_proof<H: Hash>(leaves: &[H::Output], leaf_index: u32) -> Option<Vec<H::Output>> {
+	if leaf_index as usize >= leaves.len() {
+		return None
+	}
+
+	let zero_hashes = zero_hashes::<H>();
+	let mut siblings = Vec::with_capacity(AUDIT_LOG_MERKLE_DEPTH);
+	let mut level_nodes = leaves.to_vec();
+	let mut index = leaf_index as usize;
+	for level in 0..AUDIT_LOG_MERKLE_DEPTH {
+		siblings.push(level_nodes.get(index ^ 1).cloned().unwrap_or_else(|| zero_hashes[level].clone()));
+		level_nodes = level_nodes
+			.chunks(2)
+			.map(|pair| merkle_node::<H>(&pair[0], pair.get(1).unwrap_or(&zero_hashes[level])))
+			.collect();
+		index >>= 1;
+	}
+	Some(siblings)
+}
+
+/// Checks that the leaf is at the index of the tree with the root, given its sibling path.
+pub fn verify_merkle_proof<H: Hash>(
+	root: &H::Output,
+	leaf: &H::Output,
+	leaf_index: u32,
+	siblings: &[H::Output],
+) -> bool {
+	if siblings.len() != AUDIT_LOG_MERKLE_DEPTH {
+		return false
+	}
+
+	let mut node = leaf.clone();
+	let mut index = leaf_index;
+	for sibling in siblings {
+		node = if index & 1 == 1 { merkle_node::<H>(sibling, &node) } else { merkle_node::<H>(&node, sibling) };
+		index >>= 1;
+	}
+	&node == root
+}
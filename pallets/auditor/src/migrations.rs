@@ -0,0 +1,821 @@
+//! Storage migrations for the auditor pallet.
+
+use crate::*;
+use frame_support::traits::{PalletInfoAccess, StorageVersion};
+use scale_info::prelude::vec::Vec;
+
+/// Migrates the pallet storage from whatever version is on-chain up to the current one.
+pub fn migrate<T: Config>() -> Weight {
+	let mut weight: Weight = 0;
+
+	if StorageVersion::get::<Pallet<T>>() < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+	}
+
+	if StorageVersion::get::<Pallet<T>>() < 2 {
+		weight = weight.saturating_add(v2::migrate::<T>());
+	}
+
+	if StorageVersion::get::<Pallet<T>>() < 3 {
+		weight = weight.saturating_add(v3::migrate::<T>());
+	}
+
+	if StorageVersion::get::<Pallet<T>>() < 4 {
+		weight = weight.saturating_add(v4::migrate::<T>());
+	}
+
+	if StorageVersion::get::<Pallet<T>>() < 5 {
+		weight = weight.saturating_add(v5::migrate::<T>());
+	}
+
+	if StorageVersion::get::<Pallet<T>>() < 6 {
+		weight = weight.saturating_add(v6::migrate::<T>());
+	}
+
+	if StorageVersion::get::<Pallet<T>>() < 7 {
+		weight = weight.saturating_add(v7::migrate::<T>());
+	}
+
+	if StorageVersion::get::<Pallet<T>>() < 8 {
+		weight = weight.saturating_add(v8::migrate::<T>());
+	}
+
+	if StorageVersion::get::<Pallet<T>>() < 9 {
+		weight = weight.saturating_add(v9::migrate::<T>());
+	}
+
+	weight
+}
+
+/// Version 1 gives the audit logs and ownership claims kept before it an on-chain timestamp, and
+/// replaces the flat owners list of a log file with a per-file role map.
+pub mod v1 {
+	use super::*;
+	use codec::Encode;
+	use frame_support::{storage::migration, Blake2_128Concat, StorageHasher, Twox64Concat};
+	use v2::OldAuditLog;
+
+	/// Layout of an audit log before version 1, its only timestamp being the one of the reporter.
+	#[derive(Encode, Decode)]
+	pub(crate) struct BaselineAuditLog<AccountId> {
+		pub(crate) title: Vec<u8>,
+		pub(crate) content: Vec<u8>,
+		pub(crate) timestamp: Vec<u8>,
+		pub(crate) reporter: AccountId,
+	}
+
+	/// Layout of an ownership claim before version 1, its timestamp being a placeholder.
+	#[derive(Encode, Decode)]
+	pub(crate) struct BaselineAuditLogOpenForClaim<AccountId> {
+		pub(crate) filename: Vec<u8>,
+		pub(crate) assigned_claimer: AccountId,
+		pub(crate) opened_for_claim_timestamp: u64,
+	}
+
+	#[derive(Encode)]
+	struct NewAuditLogOpenForClaim<AccountId, Moment, BlockNumber> {
+		filename: Vec<u8>,
+		assigned_claimer: AccountId,
+		opened_for_claim_timestamp: Moment,
+		expires_at: BlockNumber,
+	}
+
+	/// Moves the timestamp sent by the reporter of every audit log to its reporter timestamp, and
+	/// turns the owners of every log file into its admins.
+	///
+	/// When they were saved is not known on-chain, so audit logs and ownership claims get an
+	/// on-chain timestamp of zero. Ownership claims expire one claim window after the upgrade,
+	/// spread over as many blocks as it takes to keep within the claims expiring per block.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+		let pallet_name = <Pallet<T>>::name().as_bytes();
+
+		let baseline_audit_log_days = migration::storage_iter::<Vec<BaselineAuditLog<T::AccountId>>>(
+			pallet_name,
+			b"AuditLogStorage",
+		);
+		for (raw_key, baseline_audit_logs) in baseline_audit_log_days {
+			let audit_logs = baseline_audit_logs
+				.into_iter()
+				.map(|baseline| OldAuditLog {
+					title: baseline.title,
+					content: baseline.content,
+					reporter_timestamp: baseline.timestamp,
+					timestamp: T::Moment::default(),
+					reporter: baseline.reporter,
+				})
+				.collect::<Vec<_>>();
+			migration::put_storage_value(pallet_name, b"AuditLogStorage", &raw_key, audit_logs);
+			reads += 1;
+			writes += 1;
+		}
+
+		let first_expiry = <frame_system::Pallet<T>>::block_number() + T::ClaimWindow::get();
+		let claims_per_block = T::MaxClaimsExpiringPerBlock::get().max(1);
+		let mut expiring_log_file_names = Vec::<(T::BlockNumber, Vec<Vec<u8>>)>::new();
+		let baseline_claims = migration::storage_iter::<BaselineAuditLogOpenForClaim<T::AccountId>>(
+			pallet_name,
+			b"AuditLogOpenForClaimStorage",
+		);
+		for (index, (raw_key, baseline)) in baseline_claims.enumerate() {
+			let expires_at = first_expiry + ((index as u32) / claims_per_block).into();
+			if expiring_log_file_names.last().map_or(true, |(block, _)| *block != expires_at) {
+				expiring_log_file_names.push((expires_at, Vec::new()));
+			}
+			if let Some((_, log_file_names)) = expiring_log_file_names.last_mut() {
+				log_file_names.push(baseline.filename.clone());
+			}
+			let claim = NewAuditLogOpenForClaim {
+				filename: baseline.filename,
+				assigned_claimer: baseline.assigned_claimer,
+				opened_for_claim_timestamp: T::Moment::default(),
+				expires_at,
+			};
+			migration::put_storage_value(pallet_name, b"AuditLogOpenForClaimStorage", &raw_key, claim);
+			reads += 1;
+			writes += 1;
+		}
+		// Written with the unbounded layout of version 1, later versions migrate it further
+		for (expires_at, log_file_names) in expiring_log_file_names {
+			migration::put_storage_value(
+				pallet_name,
+				b"AuditLogClaimExpiryStorage",
+				&Twox64Concat::hash(&expires_at.encode()),
+				log_file_names,
+			);
+			writes += 1;
+		}
+
+		let old_owners = migration::storage_key_iter::<Vec<u8>, Vec<T::AccountId>, Blake2_128Concat>(
+			pallet_name,
+			b"AuditLogOwnerStorage",
+		)
+		.drain();
+
+		for (log_file_name, owners) in old_owners {
+			let roles = owners.into_iter().map(|owner| (owner, AuditLogRole::Admin)).collect::<Vec<_>>();
+			// Written with the unbounded layout of version 1, later versions migrate it further
+			migration::put_storage_value(
+				pallet_name,
+				b"AuditLogRoleStorage",
+				&Blake2_128Concat::hash(&log_file_name.encode()),
+				roles,
+			);
+			reads += 1;
+			writes += 2;
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+	}
+}
+
+/// Version 2 bounds every collection and byte string kept by the pallet.
+pub mod v2 {
+	use super::*;
+	use codec::Encode;
+	use frame_support::storage::migration;
+
+	/// Layout of an audit log before version 4, version 2 only bounds its fields.
+	#[derive(Encode, Decode)]
+	pub(crate) struct OldAuditLog<AccountId, Moment> {
+		pub(crate) title: Vec<u8>,
+		pub(crate) content: Vec<u8>,
+		pub(crate) reporter_timestamp: Vec<u8>,
+		pub(crate) timestamp: Moment,
+		pub(crate) reporter: AccountId,
+	}
+
+	/// Marker ending a field of an audit log truncated to fit within its bound.
+	pub const TRUNCATION_MARKER: &[u8] = b"[truncated]";
+
+	// Truncates the field to the bound, ending it with the marker, and tells whether it was over it
+	pub(crate) fn truncate(field: &mut Vec<u8>, bound: u32) -> bool {
+		let bound = bound as usize;
+		if field.len() <= bound {
+			return false
+		}
+		let kept = bound.saturating_sub(TRUNCATION_MARKER.len());
+		field.truncate(kept);
+		field.extend_from_slice(&TRUNCATION_MARKER[..bound - kept]);
+		true
+	}
+
+	#[derive(Decode)]
+	struct OldAuditLogOpenForClaim<AccountId, Moment, BlockNumber> {
+		filename: Vec<u8>,
+		assigned_claimer: AccountId,
+		opened_for_claim_timestamp: Moment,
+		expires_at: BlockNumber,
+	}
+
+	/// Moves the stored audit logs, roles and claims into their bounded types.
+	///
+	/// Values only differ in their type, not in their encoding, so whatever fits within the
+	/// configured bounds is kept as is. Every audit log is kept: a field over its bound is
+	/// truncated and ends with [`TRUNCATION_MARKER`], and the audit logs changed are counted in the
+	/// runtime log. A date keeps all of its audit logs, even over the entries allowed per day. A
+	/// log file keeps its admins first. Log file names and dates longer than their bound can no
+	/// longer be decoded as keys and are left untouched.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut translated: u64 = 0;
+		let mut truncated_audit_logs: u64 = 0;
+		let pallet_name = <Pallet<T>>::name().as_bytes();
+
+		let old_audit_log_days = migration::storage_iter::<Vec<OldAuditLog<T::AccountId, T::Moment>>>(
+			pallet_name,
+			b"AuditLogStorage",
+		);
+		for (raw_key, mut audit_logs) in old_audit_log_days {
+			translated += 1;
+			for audit_log in audit_logs.iter_mut() {
+				let title_truncated = truncate(&mut audit_log.title, T::MaxTitleLen::get());
+				let content_truncated = truncate(&mut audit_log.content, T::MaxContentLen::get());
+				let reporter_timestamp_truncated = truncate(&mut audit_log.reporter_timestamp, T::MaxDateLen::get());
+				if title_truncated || content_truncated || reporter_timestamp_truncated {
+					truncated_audit_logs += 1;
+				}
+			}
+			migration::put_storage_value(pallet_name, b"AuditLogStorage", &raw_key, audit_logs);
+		}
+		if truncated_audit_logs > 0 {
+			log::warn!(
+				target: "runtime::auditor",
+				"truncated {} audit logs with fields over their bound while migrating to version 2",
+				truncated_audit_logs,
+			);
+		}
+
+		AuditLogRoleStorage::<T>::translate::<Vec<(T::AccountId, AuditLogRole)>, _>(|_, mut roles| {
+			translated += 1;
+			roles.sort_by_key(|(_, role)| !role.can_manage());
+			roles.truncate(T::MaxOwners::get() as usize);
+			roles.try_into().ok()
+		});
+
+		AuditLogOpenForClaimStorage::<T>::translate::<OldAuditLogOpenForClaim<T::AccountId, T::Moment, T::BlockNumber>, _>(|_, old| {
+			translated += 1;
+			Some(AuditLogOpenForClaim::new(
+				old.filename.try_into().ok()?,
+				old.assigned_claimer,
+				old.opened_for_claim_timestamp,
+				old.expires_at,
+			))
+		});
+
+		AuditLogClaimExpiryStorage::<T>::translate::<Vec<Vec<u8>>, _>(|_, old_log_file_names| {
+			translated += 1;
+			let log_file_names = old_log_file_names
+				.into_iter()
+				.filter_map(|log_file_name| log_file_name.try_into().ok())
+				.take(T::MaxClaimsExpiringPerBlock::get() as usize)
+				.collect::<Vec<AuditLogFileName<T>>>();
+			log_file_names.try_into().ok()
+		});
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
+
+/// Version 3 keeps every audit log as its own entry instead of one vector per log file and date.
+pub mod v3 {
+	use super::*;
+	use codec::Encode;
+	use frame_support::{storage::migration, StorageHasher, Twox64Concat};
+	use v2::OldAuditLog;
+
+	/// Splits the audit logs of every log file and date into indexed entries and their count.
+	///
+	/// Entries share the hashed log file name and date of the vector they come from, so the
+	/// old key is reused as the prefix of the new ones.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+		let pallet_name = <Pallet<T>>::name().as_bytes();
+
+		let old_audit_log_days = migration::storage_iter::<Vec<OldAuditLog<T::AccountId, T::Moment>>>(
+			pallet_name,
+			b"AuditLogStorage",
+		)
+		.drain();
+		for (raw_key, old_audit_logs) in old_audit_log_days {
+			reads += 1;
+			let entry_count = old_audit_logs.len() as u32;
+			for (index, audit_log) in old_audit_logs.into_iter().enumerate() {
+				let entry_key = [raw_key.clone(), Twox64Concat::hash(&(index as u32).encode())].concat();
+				migration::put_storage_value(pallet_name, b"AuditLogEntryStorage", &entry_key, audit_log);
+				writes += 1;
+			}
+			migration::put_storage_value(pallet_name, b"AuditLogEntryCountStorage", &raw_key, entry_count);
+			writes += 2;
+		}
+
+		StorageVersion::new(3).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+	}
+}
+
+/// Version 4 lets the content of an audit log be kept off-chain, committed to by its digest.
+pub mod v4 {
+	use super::*;
+	use codec::Encode;
+	use frame_support::storage::migration;
+	use v2::OldAuditLog;
+
+	#[derive(Encode)]
+	enum NewAuditLogContent {
+		Plain(Vec<u8>),
+	}
+
+	#[derive(Encode)]
+	struct NewAuditLog<AccountId, Moment> {
+		title: Vec<u8>,
+		content: NewAuditLogContent,
+		reporter_timestamp: Vec<u8>,
+		timestamp: Moment,
+		reporter: AccountId,
+	}
+
+	/// Wraps the content of every stored audit log as plain content.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut translated: u64 = 0;
+		let pallet_name = <Pallet<T>>::name().as_bytes();
+
+		let old_audit_logs = migration::storage_iter::<OldAuditLog<T::AccountId, T::Moment>>(
+			pallet_name,
+			b"AuditLogEntryStorage",
+		);
+		for (raw_key, old) in old_audit_logs {
+			let audit_log = NewAuditLog {
+				title: old.title,
+				content: NewAuditLogContent::Plain(old.content),
+				reporter_timestamp: old.reporter_timestamp,
+				timestamp: old.timestamp,
+				reporter: old.reporter,
+			};
+			migration::put_storage_value(pallet_name, b"AuditLogEntryStorage", &raw_key, audit_log);
+			translated += 1;
+		}
+
+		StorageVersion::new(4).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
+
+/// Version 5 links the audit logs of every log file into a hash chain.
+pub mod v5 {
+	use super::*;
+	use codec::Encode;
+	use frame_support::{
+		sp_runtime::traits::Hash,
+		sp_std::collections::btree_map::BTreeMap,
+		storage::migration,
+		Blake2_128Concat,
+		StorageHasher,
+		Twox64Concat,
+	};
+
+	#[derive(Encode, Decode)]
+	pub(crate) enum OldAuditLogContent<Hash> {
+		Plain(Vec<u8>),
+		Hashed { digest: Hash, length: u32, locator: Option<Vec<u8>> },
+	}
+
+	#[derive(Encode, Decode)]
+	pub(crate) struct OldAuditLog<AccountId, Moment, Hash> {
+		title: Vec<u8>,
+		content: OldAuditLogContent<Hash>,
+		reporter_timestamp: Vec<u8>,
+		pub(crate) timestamp: Moment,
+		reporter: AccountId,
+	}
+
+	/// Layout of an audit log at version 5.
+	#[derive(Encode, Decode)]
+	pub(crate) struct NewAuditLog<AccountId, Moment, Hash> {
+		pub(crate) old: OldAuditLog<AccountId, Moment, Hash>,
+		pub(crate) sequence: u64,
+		pub(crate) previous_hash: Hash,
+	}
+
+	// Same commitment as `AuditLog::chain_hash` at version 5
+	pub(crate) fn chain_hash<T: Config>(
+		log_file_name: &[u8],
+		sequence: u64,
+		previous_hash: &T::Hash,
+		audit_log: &OldAuditLog<T::AccountId, T::Moment, T::Hash>,
+	) -> T::Hash {
+		let (content_digest, content_length) = match &audit_log.content {
+			OldAuditLogContent::Plain(content) => (T::Hashing::hash(content), content.len() as u32),
+			OldAuditLogContent::Hashed { digest, length, .. } => (*digest, *length),
+		};
+		(
+			log_file_name,
+			sequence,
+			previous_hash,
+			&audit_log.title,
+			content_digest,
+			content_length,
+			&audit_log.reporter_timestamp,
+			&audit_log.timestamp,
+			&audit_log.reporter,
+		)
+			.using_encoded(T::Hashing::hash)
+	}
+
+	// Log file name, date and index within the date encoded in the key of an audit log entry
+	pub(crate) fn decode_entry_key(raw_key: &[u8]) -> Option<(Vec<u8>, Vec<u8>, u32)> {
+		let mut input = raw_key.get(16..)?;
+		let log_file_name = Vec::<u8>::decode(&mut input).ok()?;
+		let mut input = input.get(16..)?;
+		let log_date = Vec::<u8>::decode(&mut input).ok()?;
+		let mut input = input.get(8..)?;
+		let entry_index = u32::decode(&mut input).ok()?;
+		Some((log_file_name, log_date, entry_index))
+	}
+
+	/// Chains the audit logs of every log file, ordered by their on-chain timestamp, then date
+	/// and index within the date.
+	///
+	/// Every audit log is read and written in the upgrade block, which is fine for the audit
+	/// logs kept before this version.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+		let pallet_name = <Pallet<T>>::name().as_bytes();
+
+		let mut audit_logs_by_file = BTreeMap::<Vec<u8>, Vec<_>>::new();
+		let old_audit_logs = migration::storage_iter::<OldAuditLog<T::AccountId, T::Moment, T::Hash>>(
+			pallet_name,
+			b"AuditLogEntryStorage",
+		);
+		for (raw_key, old) in old_audit_logs {
+			reads += 1;
+			if let Some((log_file_name, log_date, entry_index)) = decode_entry_key(&raw_key) {
+				audit_logs_by_file.entry(log_file_name).or_default().push((raw_key, log_date, entry_index, old));
+			}
+		}
+
+		for (log_file_name, mut audit_logs) in audit_logs_by_file {
+			audit_logs.sort_by(|a, b| (&a.3.timestamp, &a.1, a.2).cmp(&(&b.3.timestamp, &b.1, b.2)));
+
+			let hashed_log_file_name = Blake2_128Concat::hash(&log_file_name.encode());
+			let mut sequence: u64 = 0;
+			let mut previous_hash = T::Hash::default();
+			for (raw_key, log_date, entry_index, old) in audit_logs {
+				let hash = chain_hash::<T>(&log_file_name, sequence, &previous_hash, &old);
+				let audit_log = NewAuditLog { old, sequence, previous_hash };
+				migration::put_storage_value(pallet_name, b"AuditLogEntryStorage", &raw_key, audit_log);
+
+				let location_key = [hashed_log_file_name.clone(), Twox64Concat::hash(&sequence.encode())].concat();
+				migration::put_storage_value(pallet_name, b"AuditLogChainLocationStorage", &location_key, (log_date, entry_index));
+				writes += 2;
+
+				previous_hash = hash;
+				sequence += 1;
+			}
+
+			migration::put_storage_value(pallet_name, b"AuditLogChainHeadStorage", &hashed_log_file_name, (previous_hash, sequence));
+			writes += 1;
+		}
+
+		StorageVersion::new(5).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+	}
+}
+
+/// Version 6 keeps a Merkle tree over the audit logs of every log file and date.
+pub mod v6 {
+	use super::*;
+	use crate::merkle::{merkle_insert, merkle_root, AUDIT_LOG_MERKLE_DEPTH};
+	use frame_support::{sp_std::collections::btree_map::BTreeMap, storage::migration};
+	use v5::{chain_hash, decode_entry_key, NewAuditLog};
+
+	/// Builds the Merkle tree of every log file and date from the chain hashes of its audit logs.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+		let pallet_name = <Pallet<T>>::name().as_bytes();
+
+		// Keyed by the hashed log file name and date, the key of the Merkle tree
+		let mut leaves_by_date = BTreeMap::<Vec<u8>, Vec<(u32, T::Hash)>>::new();
+		let audit_logs = migration::storage_iter::<NewAuditLog<T::AccountId, T::Moment, T::Hash>>(
+			pallet_name,
+			b"AuditLogEntryStorage",
+		);
+		for (raw_key, audit_log) in audit_logs {
+			reads += 1;
+			if let Some((log_file_name, _, entry_index)) = decode_entry_key(&raw_key) {
+				let leaf = chain_hash::<T>(&log_file_name, audit_log.sequence, &audit_log.previous_hash, &audit_log.old);
+				let tree_key = raw_key[..raw_key.len() - 12].to_vec();
+				leaves_by_date.entry(tree_key).or_default().push((entry_index, leaf));
+			}
+		}
+
+		for (tree_key, mut leaves) in leaves_by_date {
+			leaves.sort_by_key(|(entry_index, _)| *entry_index);
+
+			let mut branch = [T::Hash::default(); AUDIT_LOG_MERKLE_DEPTH];
+			for (leaf_index, (_, leaf)) in leaves.iter().enumerate() {
+				merkle_insert::<T::Hashing>(&mut branch, leaf_index as u32, *leaf);
+			}
+			let root = merkle_root::<T::Hashing>(&branch, leaves.len() as u32);
+			migration::put_storage_value(pallet_name, b"AuditLogMerkleStorage", &tree_key, (root, branch));
+			writes += 1;
+		}
+
+		StorageVersion::new(6).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+	}
+}
+
+/// Version 7 gives every audit log a severity, tags and key/value attributes.
+pub mod v7 {
+	use super::*;
+	use codec::Encode;
+	use frame_support::storage::migration;
+	use v5::NewAuditLog as OldAuditLog;
+
+	/// Metadata of an audit log at version 7.
+	#[derive(Encode, Decode)]
+	pub(crate) struct AuditLogMetadata {
+		// Index of `Severity::Info`
+		severity: u8,
+		tags: Vec<Vec<u8>>,
+		attributes: Vec<(Vec<u8>, Vec<u8>)>,
+	}
+
+	/// Gives every stored audit log the default severity, no tags and no attributes.
+	///
+	/// Chain hashes and Merkle trees are left as is, audit logs without metadata hash as before.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut translated: u64 = 0;
+		let pallet_name = <Pallet<T>>::name().as_bytes();
+
+		let old_audit_logs = migration::storage_iter::<OldAuditLog<T::AccountId, T::Moment, T::Hash>>(
+			pallet_name,
+			b"AuditLogEntryStorage",
+		);
+		for (raw_key, old) in old_audit_logs {
+			let metadata = AuditLogMetadata { severity: 1, tags: Vec::new(), attributes: Vec::new() };
+			migration::put_storage_value(pallet_name, b"AuditLogEntryStorage", &raw_key, (old, metadata));
+			translated += 1;
+		}
+
+		StorageVersion::new(7).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
+
+/// Version 8 keys the audit logs of a log file by the day they were included in, counted in days
+/// since the unix epoch, instead of a date supplied by the reporter.
+pub mod v8 {
+	use super::*;
+	use crate::merkle::{merkle_insert, merkle_root, AUDIT_LOG_MERKLE_DEPTH};
+	use codec::Encode;
+	use frame_support::{
+		sp_runtime::SaturatedConversion,
+		sp_std::collections::btree_map::BTreeMap,
+		storage::migration,
+		Blake2_128Concat,
+		StorageHasher,
+		Twox64Concat,
+	};
+	use v2::truncate;
+	use v5::{decode_entry_key, NewAuditLog};
+	use v7::AuditLogMetadata;
+
+	type OldAuditLog<AccountId, Moment, Hash> = (NewAuditLog<AccountId, Moment, Hash>, AuditLogMetadata);
+
+	/// Date claimed by the reporter of an audit log from version 8 on, encoded as an `Option` of
+	/// the date but for the `Legacy` dates the audit logs saved before were stored under.
+	#[derive(Encode, Decode)]
+	pub(crate) enum ReporterDate {
+		Unclaimed,
+		Claimed(Vec<u8>),
+		Legacy(Vec<u8>),
+	}
+
+	// Days since the unix epoch of a proleptic Gregorian date
+	fn days_from_civil(year: u32, month: u32, day: u32) -> u32 {
+		let year = if month <= 2 { year - 1 } else { year };
+		let era = year / 400;
+		let year_of_era = year - era * 400;
+		let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+		let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+		era * 146_097 + day_of_era - 719_468
+	}
+
+	/// Day of a date starting with `YYYY-MM-DD`, as saved by the reporter or SCALE encoded, if
+	/// it is on or after the unix epoch.
+	pub(crate) fn parse_day(log_date: &[u8]) -> Option<u32> {
+		let log_date = match Vec::<u8>::decode(&mut &log_date[..]) {
+			Ok(decoded) if decoded.encoded_size() == log_date.len() => decoded,
+			_ => log_date.to_vec(),
+		};
+		if log_date.len() < 10 || log_date[4] != b'-' || log_date[7] != b'-' {
+			return None
+		}
+		if log_date.len() > 10 && log_date[10] != b' ' && log_date[10] != b'T' {
+			return None
+		}
+		let number = |digits: &[u8]| {
+			digits.iter().try_fold(0u32, |number, digit| match digit {
+				b'0'..=b'9' => Some(number * 10 + (digit - b'0') as u32),
+				_ => None,
+			})
+		};
+		let (year, month, day) = (number(&log_date[0..4])?, number(&log_date[5..7])?, number(&log_date[8..10])?);
+
+		let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+		let days_in_month = match month {
+			1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+			4 | 6 | 9 | 11 => 30,
+			2 if leap_year => 29,
+			2 => 28,
+			_ => return None,
+		};
+		if year < 1970 || day == 0 || day > days_in_month {
+			return None
+		}
+		Some(days_from_civil(year, month, day))
+	}
+
+	/// Moves every audit log under the day of its date, or the day of its on-chain timestamp
+	/// when the date cannot be parsed, and rebuilds the counts, chain locations and Merkle trees.
+	///
+	/// Audit logs of a day keep the order of the chain of their log file. The date of every audit
+	/// log, parsed or not, is kept as is as its legacy claimed date, truncated like the fields of
+	/// version 2 when over the bound of the dates. Chain hashes are left as is: a legacy claimed
+	/// date is not committed to by the chain hash, the date never having been part of it, so the
+	/// chains saved before still verify. Every audit log is read and written in the upgrade block,
+	/// as for the previous versions.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+		let pallet_name = <Pallet<T>>::name().as_bytes();
+
+		let mut audit_logs_by_file = BTreeMap::<Vec<u8>, BTreeMap<u64, (u32, OldAuditLog<T::AccountId, T::Moment, T::Hash>, Vec<u8>)>>::new();
+		let old_audit_logs = migration::storage_iter::<OldAuditLog<T::AccountId, T::Moment, T::Hash>>(
+			pallet_name,
+			b"AuditLogEntryStorage",
+		)
+		.drain();
+		for (raw_key, old) in old_audit_logs {
+			reads += 1;
+			writes += 1;
+			if let Some((log_file_name, log_date, _)) = decode_entry_key(&raw_key) {
+				let log_day = parse_day(&log_date)
+					.unwrap_or_else(|| (old.0.old.timestamp.saturated_into::<u64>() / MILLISECS_PER_DAY) as u32);
+				let mut claimed_date = log_date;
+				truncate(&mut claimed_date, T::MaxDateLen::get());
+				audit_logs_by_file.entry(log_file_name).or_default().insert(old.0.sequence, (log_day, old, claimed_date));
+			}
+		}
+		migration::remove_storage_prefix(pallet_name, b"AuditLogEntryCountStorage", &[]);
+		migration::remove_storage_prefix(pallet_name, b"AuditLogMerkleStorage", &[]);
+		migration::remove_storage_prefix(pallet_name, b"AuditLogChainLocationStorage", &[]);
+		writes += 3;
+
+		for (log_file_name, audit_logs) in audit_logs_by_file {
+			let hashed_log_file_name = Blake2_128Concat::hash(&log_file_name.encode());
+			let chain_head = migration::get_storage_value::<(T::Hash, u64)>(
+				pallet_name,
+				b"AuditLogChainHeadStorage",
+				&hashed_log_file_name,
+			)
+			.unwrap_or_default();
+			reads += 1;
+
+			// The chain hash of an audit log is the previous hash of the one after it, or the chain head
+			let mut leaves_by_day = BTreeMap::<u32, Vec<T::Hash>>::new();
+			for (sequence, (log_day, (audit_log, metadata), claimed_date)) in audit_logs.iter() {
+				let leaf = match audit_logs.get(&(sequence + 1)) {
+					Some((_, (next_audit_log, _), _)) => next_audit_log.previous_hash,
+					None => chain_head.0,
+				};
+				let leaves = leaves_by_day.entry(*log_day).or_default();
+				let entry_index = leaves.len() as u32;
+				leaves.push(leaf);
+
+				let hashed_log_day = Twox64Concat::hash(&log_day.encode());
+				let entry_key = [hashed_log_file_name.clone(), hashed_log_day, Twox64Concat::hash(&entry_index.encode())].concat();
+				migration::put_storage_value(pallet_name, b"AuditLogEntryStorage", &entry_key, (audit_log, metadata, ReporterDate::Legacy(claimed_date.clone())));
+
+				let location_key = [hashed_log_file_name.clone(), Twox64Concat::hash(&sequence.encode())].concat();
+				migration::put_storage_value(pallet_name, b"AuditLogChainLocationStorage", &location_key, (log_day, entry_index));
+				writes += 2;
+			}
+
+			for (log_day, leaves) in leaves_by_day {
+				let mut branch = [T::Hash::default(); AUDIT_LOG_MERKLE_DEPTH];
+				for (leaf_index, leaf) in leaves.iter().enumerate() {
+					merkle_insert::<T::Hashing>(&mut branch, leaf_index as u32, *leaf);
+				}
+				let root = merkle_root::<T::Hashing>(&branch, leaves.len() as u32);
+
+				let day_key = [hashed_log_file_name.clone(), Twox64Concat::hash(&log_day.encode())].concat();
+				migration::put_storage_value(pallet_name, b"AuditLogEntryCountStorage", &day_key, leaves.len() as u32);
+				migration::put_storage_value(pallet_name, b"AuditLogMerkleStorage", &day_key, (root, branch));
+				writes += 2;
+			}
+		}
+
+		StorageVersion::new(8).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+	}
+}
+
+/// Version 9 gives the encrypted content of every audit log the key epoch its content key is
+/// sealed in.
+pub mod v9 {
+	use super::*;
+	use codec::Encode;
+	use frame_support::storage::migration;
+
+	/// Content of an audit log, with a key epoch from version 9 on, when `KeyEpoch` is `u32`
+	/// rather than `()`.
+	#[derive(Encode, Decode)]
+	pub(crate) enum VersionedAuditLogContent<AccountId, Moment, Hash, KeyEpoch> {
+		Plain(Vec<u8>),
+		Hashed { digest: Hash, length: u32, locator: Option<Vec<u8>> },
+		Redacted { digest: Hash, length: u32, redacted_by: Option<AccountId>, redacted_at: Moment, reason_hash: Hash },
+		Encrypted {
+			ciphertext: Vec<u8>,
+			nonce: [u8; 12],
+			ephemeral_public_key: [u8; 32],
+			wrapped_keys: Vec<(AccountId, [u8; 48])>,
+			key_epoch: KeyEpoch,
+		},
+	}
+
+	/// Audit log at version 8 or 9, depending on its content.
+	#[derive(Encode, Decode)]
+	pub(crate) struct VersionedAuditLog<AccountId, Moment, Hash, KeyEpoch> {
+		title: Vec<u8>,
+		content: VersionedAuditLogContent<AccountId, Moment, Hash, KeyEpoch>,
+		reporter_timestamp: Vec<u8>,
+		timestamp: Moment,
+		reporter: AccountId,
+		sequence: u64,
+		previous_hash: Hash,
+		// Index of the severity
+		severity: u8,
+		tags: Vec<Vec<u8>>,
+		attributes: Vec<(Vec<u8>, Vec<u8>)>,
+		claimed_date: v8::ReporterDate,
+	}
+
+	/// Puts the encrypted content of every audit log in key epoch zero, the one before the first
+	/// key epoch of a log file.
+	///
+	/// Only the audit logs with encrypted content are written, the others are left as is.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+		let pallet_name = <Pallet<T>>::name().as_bytes();
+
+		let old_audit_logs = migration::storage_iter::<VersionedAuditLog<T::AccountId, T::Moment, T::Hash, ()>>(
+			pallet_name,
+			b"AuditLogEntryStorage",
+		);
+		for (raw_key, old) in old_audit_logs {
+			reads += 1;
+			let (ciphertext, nonce, ephemeral_public_key, wrapped_keys) = match old.content {
+				VersionedAuditLogContent::Encrypted { ciphertext, nonce, ephemeral_public_key, wrapped_keys, .. } => {
+					(ciphertext, nonce, ephemeral_public_key, wrapped_keys)
+				},
+				_ => continue,
+			};
+			let audit_log = VersionedAuditLog {
+				title: old.title,
+				content: VersionedAuditLogContent::Encrypted { ciphertext, nonce, ephemeral_public_key, wrapped_keys, key_epoch: 0u32 },
+				reporter_timestamp: old.reporter_timestamp,
+				timestamp: old.timestamp,
+				reporter: old.reporter,
+				sequence: old.sequence,
+				previous_hash: old.previous_hash,
+				severity: old.severity,
+				tags: old.tags,
+				attributes: old.attributes,
+				claimed_date: old.claimed_date,
+			};
+			migration::put_storage_value(pallet_name, b"AuditLogEntryStorage", &raw_key, audit_log);
+			writes += 1;
+		}
+
+		StorageVersion::new(9).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+	}
+}
@@ -18,6 +18,8 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Auditor: auditor_pallet::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -45,7 +47,7 @@ impl system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -53,11 +55,93 @@ impl system::Config for Test {
 	type OnSetCode = ();
 }
 
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const ClaimWindow: u64 = 10;
+	pub const MaxFileNameLen: u32 = 32;
+	pub const MaxDateLen: u32 = 32;
+	pub const MaxTitleLen: u32 = 32;
+	pub const MaxContentLen: u32 = 64;
+	pub const MaxLocatorLen: u32 = 32;
+	pub const MaxOwners: u32 = 3;
+	pub const MaxEntriesPerDay: u32 = 3;
+	pub const MaxClaimsExpiringPerBlock: u32 = 2;
+	pub const MaxTags: u32 = 4;
+	pub const MaxTagLen: u32 = 16;
+	pub const MaxAttributes: u32 = 4;
+	pub const MaxAttributeKeyLen: u32 = 16;
+	pub const MaxAttributeValueLen: u32 = 32;
+	pub const MaxBatchSize: u32 = 4;
+	pub const MaxReaders: u32 = 3;
+	pub const MaxReporterFieldLen: u32 = 16;
+	pub const RateLimitWindow: u64 = 10;
+	pub const DepositPerItem: u64 = 10;
+	pub const DepositPerByte: u64 = 1;
+}
+
 impl auditor_pallet::Config for Test {
 	type Event = Event;
+	type ClaimWindow = ClaimWindow;
+	type MaxFileNameLen = MaxFileNameLen;
+	type MaxDateLen = MaxDateLen;
+	type MaxTitleLen = MaxTitleLen;
+	type MaxContentLen = MaxContentLen;
+	type MaxLocatorLen = MaxLocatorLen;
+	type MaxOwners = MaxOwners;
+	type MaxEntriesPerDay = MaxEntriesPerDay;
+	type MaxClaimsExpiringPerBlock = MaxClaimsExpiringPerBlock;
+	type MaxTags = MaxTags;
+	type MaxTagLen = MaxTagLen;
+	type MaxAttributes = MaxAttributes;
+	type MaxAttributeKeyLen = MaxAttributeKeyLen;
+	type MaxAttributeValueLen = MaxAttributeValueLen;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxReaders = MaxReaders;
+	type MaxReporterFieldLen = MaxReporterFieldLen;
+	type RateLimitWindow = RateLimitWindow;
+	type Currency = Balances;
+	type DepositPerItem = DepositPerItem;
+	type DepositPerByte = DepositPerByte;
+	type LegalHoldOrigin = system::EnsureRoot<u64>;
+	type PrivacyOfficerOrigin = system::EnsureRoot<u64>;
+	type FeelessReporterOrigin = system::EnsureRoot<u64>;
+	type WeightInfo = ();
 }
 
+// Balance of the accounts 1 to 3 at genesis, the other accounts have none
+pub const INITIAL_BALANCE: u64 = 1_000_000;
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: (1..=3).map(|account| (account, INITIAL_BALANCE)).collect() }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	storage.into()
 }
\ No newline at end of file
@@ -1,21 +1,49 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, AuditLogBatchEntry, AuditLogContent, AuditLogContentType, AuditLogDay, AuditLogFileName, AuditLogMetadata, AuditLogRateLimit, AuditLogRole, Error, Severity, MILLISECS_PER_DAY};
+use core::convert::TryInto;
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::{Pays, Weight}};
 use codec::Encode;
+use crate::feeless::{CheckAuditLogQuota, AUDIT_LOG_QUOTA_EXCEEDED, DELEGATE_QUOTA_EXCEEDED};
+use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction};
+
+// Builds the raw public key that decodes into the given mock account id
+fn claimer_pubkey(account: u64) -> [u8; 32] {
+	let mut pubkey = [0u8; 32];
+	pubkey[..8].copy_from_slice(&account.encode());
+	pubkey
+}
+
+// Builds the bounded log file name the pallet keys its storage with
+fn file_name(name: &str) -> AuditLogFileName<Test> {
+	name.encode().try_into().unwrap()
+}
+
+// Day of the blocks of the tests, the mock timestamp being the unix epoch unless set
+const TODAY: AuditLogDay = 0;
+
+// Saves a log on "log-file-name", making the sender its owner
+fn save_default_audit_log(sender: u64) {
+	let log_file_name = "log-file-name".encode();
+	let date = Some("2021-10-08".encode());
+	let title = "log-title".encode();
+	let content = "transaction with id 123 is processed".encode();
+	let timestamp = "2021-10-08 17:30:00 UTC".encode();
+	assert_ok!(Auditor::save_audit_log(Origin::signed(sender), log_file_name, date, title, content, timestamp));
+}
 
 #[test]
 fn save_audit_log_one_item() {
 	new_test_ext().execute_with(|| {
 		let sender = Origin::signed(1);
-		let file_name = "log-file-name".encode();
-		let date = "2021-10-08".encode();
+		let log_file_name = "log-file-name".encode();
+		let date = Some("2021-10-08".encode());
 		let title = "log-title".encode();
 		let content = "transaction with id 123 is processed".encode();
 		let timestamp = "2021-10-08 17:30:00 UTC".encode();
 		// Dispatch a signed extrinsic.
-		assert_ok!(Auditor::save_audit_log(sender, file_name, date, title, content, timestamp));
+		assert_ok!(Auditor::save_audit_log(sender, log_file_name, date, title, content, timestamp));
 
-		let retrieve_file_name = "log-file-name".encode();
-		let retrieve_date = "2021-10-08".encode();
+		let retrieve_file_name = file_name("log-file-name");
+		let retrieve_date = TODAY;
 		let audit_logs = Auditor::retrieve_audit_log(retrieve_file_name, retrieve_date);
 
 		for result in audit_logs {
@@ -26,7 +54,7 @@ fn save_audit_log_one_item() {
 			//assert_eq!(&result.get_reporter(), &1);
 		} 
 
-		assert_eq!(Auditor::retrieve_audit_log("log-file-name".encode(), "2021-10-08".encode()).len(), 1);
+		assert_eq!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY).len(), 1);
 	});
 }
 
@@ -34,25 +62,25 @@ fn save_audit_log_one_item() {
 fn save_audit_log_with_sender_saving_two_items_on_same_logname_it_owns() {
 	new_test_ext().execute_with(|| {
 		let sender = Origin::signed(1);
-		let file_name = "log-file-name".encode();
-		let date = "2021-10-08".encode();
+		let log_file_name = "log-file-name".encode();
+		let date = Some("2021-10-08".encode());
 		let title = "log-title".encode();
 		let content = "transaction with id 123 is processed".encode();
 		let timestamp = "2021-10-08 17:30:00 UTC".encode();
 		// Dispatch a signed extrinsic.
-		assert_ok!(Auditor::save_audit_log(sender, file_name, date, title, content, timestamp));
+		assert_ok!(Auditor::save_audit_log(sender, log_file_name, date, title, content, timestamp));
 
 		let sender2 = Origin::signed(1);
 		let file_name2 = "log-file-name".encode();
-		let date2 = "2021-10-08".encode();
+		let date2 = Some("2021-10-08".encode());
 		let title2 = "log-title".encode();
 		let content2 = "transaction with id 123 is processed".encode();
 		let timestamp2 = "2021-10-08 17:45:00 UTC".encode();
 		// Dispatch a signed extrinsic.
 		assert_ok!(Auditor::save_audit_log(sender2, file_name2, date2, title2, content2, timestamp2));
 
-		let retrieve_file_name = "log-file-name".encode();
-		let retrieve_date = "2021-10-08".encode();
+		let retrieve_file_name = file_name("log-file-name");
+		let retrieve_date = TODAY;
 		assert_eq!(Auditor::retrieve_audit_log(retrieve_file_name, retrieve_date).len(), 2);
 	});
 }
@@ -61,17 +89,17 @@ fn save_audit_log_with_sender_saving_two_items_on_same_logname_it_owns() {
 fn dont_save_audit_log_if_a_sender_saves_on_already_taken_log() {
 	new_test_ext().execute_with(|| {
 		let sender = Origin::signed(1);
-		let file_name = "log-file-name".encode();
-		let date = "2021-10-08".encode();
+		let log_file_name = "log-file-name".encode();
+		let date = Some("2021-10-08".encode());
 		let title = "log-title".encode();
 		let content = "transaction with id 123 is processed".encode();
 		let timestamp = "2021-10-08 17:30:00 UTC".encode();
 		// Dispatch a signed extrinsic.
-		assert_ok!(Auditor::save_audit_log(sender, file_name, date, title, content, timestamp));
+		assert_ok!(Auditor::save_audit_log(sender, log_file_name, date, title, content, timestamp));
 
 		let sender2 = Origin::signed(2);
 		let file_name2 = "log-file-name".encode();
-		let date2 = "2021-10-08".encode();
+		let date2 = Some("2021-10-08".encode());
 		let title2 = "log-title".encode();
 		let content2 = "transaction with id 123 is processed".encode();
 		let timestamp2 = "2021-10-08 17:45:00 UTC".encode();
@@ -84,25 +112,25 @@ fn dont_save_audit_log_if_a_sender_saves_on_already_taken_log() {
 fn save_audit_log_two_items_but_different_keys() {
 	new_test_ext().execute_with(|| {
 		let sender = Origin::signed(1);
-		let file_name = "log-file-name".encode();
-		let date = "2021-10-08".encode();
+		let log_file_name = "log-file-name".encode();
+		let date = Some("2021-10-08".encode());
 		let title = "log-title".encode();
 		let content = "transaction with id 123 is processed".encode();
 		let timestamp = "2021-10-08 17:30:00 UTC".encode();
 		// Dispatch a signed extrinsic.
-		assert_ok!(Auditor::save_audit_log(sender, file_name, date, title, content, timestamp));
+		assert_ok!(Auditor::save_audit_log(sender, log_file_name, date, title, content, timestamp));
 
 		let sender2 = Origin::signed(1);
 		let file_name2 = "different-file-name".encode();
-		let date2 = "2021-10-08".encode();
+		let date2 = Some("2021-10-08".encode());
 		let title2 = "log-title".encode();
 		let content2 = "transaction with id 123 is processed".encode();
 		let timestamp2 = "2021-10-08 17:45:00 UTC".encode();
 		// Dispatch a signed extrinsic.
 		assert_ok!(Auditor::save_audit_log(sender2, file_name2, date2, title2, content2, timestamp2));
 
-		let retrieve_file_name = "log-file-name".encode();
-		let retrieve_date = "2021-10-08".encode();
+		let retrieve_file_name = file_name("log-file-name");
+		let retrieve_date = TODAY;
 		assert_eq!(Auditor::retrieve_audit_log(retrieve_file_name, retrieve_date).len(), 1);
 	});
 }
@@ -113,12 +141,12 @@ fn open_log_for_ownership_claim() {
 
 		// SETUP to have an audit log saved
 		let sender = Origin::signed(1);
-		let file_name = "log-file-name".encode();
-		let date = "2021-10-08".encode();
+		let log_file_name = "log-file-name".encode();
+		let date = Some("2021-10-08".encode());
 		let title = "log-title".encode();
 		let content = "transaction with id 123 is processed".encode();
 		let timestamp = "2021-10-08 17:30:00 UTC".encode();
-		assert_ok!(Auditor::save_audit_log(sender, file_name, date, title, content, timestamp));
+		assert_ok!(Auditor::save_audit_log(sender, log_file_name, date, title, content, timestamp));
 
 		// ASSERT
 		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), [0, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1]));
@@ -134,13 +162,13 @@ fn claim_ownership_of_opened_log() {
 
 		// SETUP to have an audit log saved
 		let sender = Origin::signed(1);
-		let file_name = "log-file-name".encode();
-		let date = "2021-10-08".encode();
+		let log_file_name = "log-file-name".encode();
+		let date = Some("2021-10-08".encode());
 		let title = "log-title".encode();
 		let content = "transaction with id 123 is processed".encode();
 		let timestamp = "2021-10-08 17:30:00 UTC".encode();
 		// Dispatch a signed extrinsic.
-		assert_ok!(Auditor::save_audit_log(sender, file_name, date, title, content, timestamp));
+		assert_ok!(Auditor::save_audit_log(sender, log_file_name, date, title, content, timestamp));
 
 		// TODO: need to come up with a way to make the claimer id a real one, instead of random [u8; 32]
 		// ASSERT
@@ -149,4 +177,1401 @@ fn claim_ownership_of_opened_log() {
 		
 		// --------
 	});
-}
\ No newline at end of file
+}
+#[test]
+fn save_audit_log_records_block_timestamp_and_reporter_timestamp() {
+	new_test_ext().execute_with(|| {
+		Timestamp::set_timestamp(1_633_714_200_000);
+
+		let sender = Origin::signed(1);
+		let log_file_name = "log-file-name".encode();
+		let date = Some("2021-10-08".encode());
+		let title = "log-title".encode();
+		let content = "transaction with id 123 is processed".encode();
+		let timestamp = "2021-10-08 17:30:00 UTC".encode();
+		assert_ok!(Auditor::save_audit_log(sender, log_file_name, date, title, content, timestamp));
+
+		let audit_logs = Auditor::retrieve_audit_log(file_name("log-file-name"), 18_908);
+		assert_eq!(audit_logs[0].clone().get_timestamp(), 1_633_714_200_000);
+		assert_eq!(audit_logs[0].clone().get_reporter_timestamp(), "2021-10-08 17:30:00 UTC".encode());
+		assert_eq!(audit_logs[0].clone().get_claimed_date(), Some("2021-10-08".encode()));
+	});
+}
+
+#[test]
+fn save_audit_log_under_the_day_of_its_block_whatever_the_claimed_date() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// 2021-10-08 23:59:59.999 UTC, then the first millisecond of 2021-10-09
+		Timestamp::set_timestamp(1_633_737_599_999);
+		assert_eq!(Auditor::current_day(), 18_908);
+		assert_ok!(Auditor::save_audit_log(Origin::signed(1), "log-file-name".encode(), Some("2021-10-01".encode()), "log-title".encode(), "first".encode(), "2021-10-01 08:00:00 UTC".encode()));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogInformationStored(file_name("log-file-name"), 18_908, 1, Severity::Info, Default::default(), Default::default())));
+		Timestamp::set_timestamp(1_633_737_600_000);
+		assert_ok!(Auditor::save_audit_log(Origin::signed(1), "log-file-name".encode(), None, "log-title".encode(), "second".encode(), "2021-10-09 00:00:00 UTC".encode()));
+
+		assert_eq!(Auditor::retrieve_audit_log(file_name("log-file-name"), 18_901).len(), 0);
+		let first_day = Auditor::retrieve_audit_log(file_name("log-file-name"), 18_908);
+		assert_eq!(first_day.len(), 1);
+		assert_eq!(first_day[0].clone().get_claimed_date(), Some("2021-10-01".encode()));
+		let second_day = Auditor::retrieve_audit_log(file_name("log-file-name"), 18_909);
+		assert_eq!(second_day.len(), 1);
+		assert_eq!(second_day[0].clone().get_claimed_date(), None);
+		assert!(Auditor::verify_chain("log-file-name".encode(), 0, 1));
+
+		assert_noop!(Auditor::save_audit_log(Origin::signed(1), "log-file-name".encode(), Some(vec![0u8; 33]), "log-title".encode(), "third".encode(), "2021-10-09 00:00:00 UTC".encode()), Error::<Test>::AuditLogDateTooLong);
+	});
+}
+
+#[test]
+fn open_log_for_ownership_claim_records_block_timestamp() {
+	new_test_ext().execute_with(|| {
+		Timestamp::set_timestamp(1_633_714_200_000);
+
+		let sender = Origin::signed(1);
+		let log_file_name = "log-file-name".encode();
+		let date = Some("2021-10-08".encode());
+		let title = "log-title".encode();
+		let content = "transaction with id 123 is processed".encode();
+		let timestamp = "2021-10-08 17:30:00 UTC".encode();
+		assert_ok!(Auditor::save_audit_log(sender, log_file_name, date, title, content, timestamp));
+
+		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), [0, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1]));
+
+		let open_for_claim = Auditor::retrieve_audit_log_open_for_claim(file_name("log-file-name"));
+		assert_eq!(open_for_claim.get_opened_for_claim_timestamp(), 1_633_714_200_000);
+	});
+}
+
+#[test]
+fn claim_log_within_claim_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(2)));
+
+		System::set_block_number(10);
+		assert_ok!(Auditor::claim_log(Origin::signed(2), "log-file-name".encode()));
+		assert_eq!(Auditor::retrieve_audit_log_owner(file_name("log-file-name")), vec![1, 2]);
+	});
+}
+
+#[test]
+fn dont_claim_log_after_claim_window_expired() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(2)));
+
+		System::set_block_number(11);
+		assert_noop!(Auditor::claim_log(Origin::signed(2), "log-file-name".encode()), Error::<Test>::AuditLogClaimHasExpired);
+	});
+}
+
+#[test]
+fn expired_claim_is_cleaned_up_and_log_can_be_opened_again() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(2)));
+
+		System::set_block_number(11);
+		Auditor::on_initialize(11);
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogClaimExpired(file_name("log-file-name"), 2)));
+		assert_noop!(Auditor::claim_log(Origin::signed(2), "log-file-name".encode()), Error::<Test>::AuditLogNotOpenedForClaiming);
+
+		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(3)));
+	});
+}
+
+#[test]
+fn cancel_ownership_claim_allows_opening_the_log_again() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(2)));
+
+		assert_ok!(Auditor::cancel_ownership_claim(Origin::signed(1), "log-file-name".encode()));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogOwnershipClaimCancelled(file_name("log-file-name"), 1)));
+		assert_noop!(Auditor::claim_log(Origin::signed(2), "log-file-name".encode()), Error::<Test>::AuditLogNotOpenedForClaiming);
+
+		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(3)));
+	});
+}
+
+#[test]
+fn dont_cancel_ownership_claim_if_sender_is_not_an_owner() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(2)));
+
+		assert_noop!(Auditor::cancel_ownership_claim(Origin::signed(2), "log-file-name".encode()), Error::<Test>::NoRightsToChangeAuditLogClaim);
+		assert_noop!(Auditor::cancel_ownership_claim(Origin::signed(1), "unknown-file-name".encode()), Error::<Test>::AuditLogCantBeFound);
+	});
+}
+
+#[test]
+fn replace_ownership_claim_assigns_another_claimer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(2)));
+
+		assert_ok!(Auditor::replace_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(3)));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogOwnershipClaimReplaced(file_name("log-file-name"), 1)));
+
+		assert_noop!(Auditor::claim_log(Origin::signed(2), "log-file-name".encode()), Error::<Test>::NotAuthorizedToClaimAuditLog);
+		assert_ok!(Auditor::claim_log(Origin::signed(3), "log-file-name".encode()));
+		assert_noop!(Auditor::replace_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(2)), Error::<Test>::AuditLogNotOpenedForClaiming);
+	});
+}
+
+#[test]
+fn cancelled_and_replaced_claims_free_their_place_among_the_expiring_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		for _ in 0..MaxClaimsExpiringPerBlock::get() + 1 {
+			assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(2)));
+			assert_ok!(Auditor::cancel_ownership_claim(Origin::signed(1), "log-file-name".encode()));
+		}
+		assert!(crate::AuditLogClaimExpiryStorage::<Test>::get(11).is_empty());
+
+		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(2)));
+		for _ in 0..MaxClaimsExpiringPerBlock::get() + 1 {
+			assert_ok!(Auditor::replace_ownership_claim(Origin::signed(1), "log-file-name".encode(), claimer_pubkey(3)));
+		}
+		assert_eq!(crate::AuditLogClaimExpiryStorage::<Test>::get(11).into_inner(), vec![file_name("log-file-name")]);
+
+		// Another owner can still open a claim expiring in the same block
+		assert_ok!(Auditor::save_audit_log(Origin::signed(2), "other-log-file-name".encode(), None, "log-title".encode(), "content".encode(), "2021-10-08 17:30:00 UTC".encode()));
+		assert_ok!(Auditor::open_log_for_ownership_claim(Origin::signed(2), "other-log-file-name".encode(), claimer_pubkey(3)));
+	});
+}
+
+#[test]
+fn writer_can_save_audit_log_but_cannot_manage_the_log() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Writer));
+
+		save_default_audit_log(2);
+		assert_eq!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY).len(), 2);
+
+		assert_noop!(Auditor::open_log_for_ownership_claim(Origin::signed(2), "log-file-name".encode(), claimer_pubkey(3)), Error::<Test>::NoRightsToOpenAuditLogForClaiming);
+		assert_noop!(Auditor::grant_log_role(Origin::signed(2), "log-file-name".encode(), 3, AuditLogRole::Writer), Error::<Test>::NoRightsToManageAuditLogRoles);
+	});
+}
+
+#[test]
+fn reader_cannot_save_audit_log() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Reader));
+
+		let log_file_name = "log-file-name".encode();
+		let date = Some("2021-10-08".encode());
+		let title = "log-title".encode();
+		let content = "transaction with id 123 is processed".encode();
+		let timestamp = "2021-10-08 17:30:00 UTC".encode();
+		assert_noop!(Auditor::save_audit_log(Origin::signed(2), log_file_name, date, title, content, timestamp), Error::<Test>::AuditLogIdentifierCannotBeUsed);
+	});
+}
+
+#[test]
+fn auditor_can_attest_audit_log() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Auditor));
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 3, AuditLogRole::Writer));
+
+		assert_ok!(Auditor::attest_audit_log(Origin::signed(2), "log-file-name".encode(), TODAY));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogAttested(file_name("log-file-name"), TODAY, 2)));
+
+		assert_noop!(Auditor::attest_audit_log(Origin::signed(3), "log-file-name".encode(), TODAY), Error::<Test>::NoRightsToAttestAuditLog);
+		assert_noop!(Auditor::attest_audit_log(Origin::signed(2), "log-file-name".encode(), TODAY + 1), Error::<Test>::AuditLogCantBeFound);
+	});
+}
+
+#[test]
+fn revoke_log_role_keeps_at_least_one_admin() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Writer));
+
+		assert_ok!(Auditor::revoke_log_role(Origin::signed(1), "log-file-name".encode(), 2));
+		assert_eq!(Auditor::retrieve_audit_log_roles(file_name("log-file-name")).into_inner(), vec![(1, AuditLogRole::Admin)]);
+
+		assert_noop!(Auditor::revoke_log_role(Origin::signed(1), "log-file-name".encode(), 1), Error::<Test>::AuditLogMustKeepAnAdmin);
+		assert_noop!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 1, AuditLogRole::Writer), Error::<Test>::AuditLogMustKeepAnAdmin);
+		assert_noop!(Auditor::revoke_log_role(Origin::signed(1), "log-file-name".encode(), 2), Error::<Test>::AccountHasNoAuditLogRole);
+	});
+}
+
+#[test]
+fn migrate_owners_to_admins() {
+	use frame_support::{storage::migration, Blake2_128Concat, StorageHasher};
+
+	new_test_ext().execute_with(|| {
+		let raw_file_name = "log-file-name".encode();
+		migration::put_storage_value(b"Auditor", b"AuditLogOwnerStorage", &Blake2_128Concat::hash(&raw_file_name.encode()), vec![1u64, 2u64]);
+
+		crate::migrations::migrate::<Test>();
+
+		assert_eq!(Auditor::retrieve_audit_log_roles(file_name("log-file-name")).into_inner(), vec![(1, AuditLogRole::Admin), (2, AuditLogRole::Admin)]);
+		assert_eq!(migration::get_storage_value::<Vec<u64>>(b"Auditor", b"AuditLogOwnerStorage", &Blake2_128Concat::hash(&raw_file_name.encode())), None);
+	});
+}
+
+#[test]
+fn migrate_baseline_audit_logs_and_claims_to_on_chain_timestamps() {
+	use crate::migrations::v1::{BaselineAuditLog, BaselineAuditLogOpenForClaim};
+	use frame_support::{storage::migration, Blake2_128Concat, StorageHasher};
+
+	new_test_ext().execute_with(|| {
+		let raw_file_name = "log-file-name".encode();
+		let raw_date = "2021-10-08".encode();
+		let key = [Blake2_128Concat::hash(&raw_file_name.encode()), Blake2_128Concat::hash(&raw_date.encode())].concat();
+		let baseline_audit_log = BaselineAuditLog { title: "log-title".encode(), content: "content".encode(), timestamp: "2021-10-08 17:30:00 UTC".encode(), reporter: 1u64 };
+		migration::put_storage_value(b"Auditor", b"AuditLogStorage", &key, vec![baseline_audit_log]);
+		migration::put_storage_value(b"Auditor", b"AuditLogOwnerStorage", &Blake2_128Concat::hash(&raw_file_name.encode()), vec![1u64]);
+		let baseline_claim = BaselineAuditLogOpenForClaim { filename: raw_file_name.clone(), assigned_claimer: 2u64, opened_for_claim_timestamp: 123456789 };
+		migration::put_storage_value(b"Auditor", b"AuditLogOpenForClaimStorage", &Blake2_128Concat::hash(&raw_file_name.encode()), baseline_claim);
+
+		crate::migrations::migrate::<Test>();
+
+		let audit_log = Auditor::retrieve_audit_log_entry((file_name("log-file-name"), 18_908, 0)).unwrap();
+		assert_eq!(audit_log.clone().get_reporter_timestamp(), "2021-10-08 17:30:00 UTC".encode());
+		assert_eq!(audit_log.clone().get_timestamp(), 0);
+		assert_eq!(audit_log.get_reporter(), 1);
+		assert!(Auditor::verify_chain("log-file-name".encode(), 0, 0));
+
+		let claim = Auditor::retrieve_audit_log_open_for_claim(file_name("log-file-name"));
+		assert_eq!(claim.clone().get_assigned_claimer(), 2);
+		assert_eq!(claim.clone().get_opened_for_claim_timestamp(), 0);
+		assert_eq!(claim.get_expires_at(), ClaimWindow::get());
+		assert_eq!(crate::AuditLogClaimExpiryStorage::<Test>::get(ClaimWindow::get()).into_inner(), vec![file_name("log-file-name")]);
+		assert_ok!(Auditor::claim_log(Origin::signed(2), "log-file-name".encode()));
+	});
+}
+
+#[test]
+fn remove_owner_of_log() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Admin));
+
+		assert_ok!(Auditor::remove_owner(Origin::signed(1), "log-file-name".encode(), 2));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogOwnerRemoved(file_name("log-file-name"), 2, 1)));
+		assert_eq!(Auditor::retrieve_audit_log_owner(file_name("log-file-name")), vec![1]);
+	});
+}
+
+#[test]
+fn dont_remove_owner_if_sender_is_not_an_owner() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Writer));
+
+		assert_noop!(Auditor::remove_owner(Origin::signed(2), "log-file-name".encode(), 1), Error::<Test>::NoRightsToManageAuditLogRoles);
+		assert_noop!(Auditor::remove_owner(Origin::signed(1), "unknown-file-name".encode(), 1), Error::<Test>::AuditLogCantBeFound);
+	});
+}
+
+#[test]
+fn dont_remove_owner_if_account_is_not_an_owner() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Writer));
+
+		assert_noop!(Auditor::remove_owner(Origin::signed(1), "log-file-name".encode(), 2), Error::<Test>::AccountIsNotAuditLogOwner);
+		assert_noop!(Auditor::remove_owner(Origin::signed(1), "log-file-name".encode(), 3), Error::<Test>::AccountIsNotAuditLogOwner);
+	});
+}
+
+#[test]
+fn dont_remove_the_last_owner_of_log() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Writer));
+
+		assert_noop!(Auditor::remove_owner(Origin::signed(1), "log-file-name".encode(), 1), Error::<Test>::AuditLogMustKeepAnAdmin);
+	});
+}
+
+#[test]
+fn renounce_ownership_of_log() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Admin));
+
+		assert_ok!(Auditor::renounce_ownership(Origin::signed(1), "log-file-name".encode()));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogOwnershipRenounced(file_name("log-file-name"), 1)));
+		assert_eq!(Auditor::retrieve_audit_log_roles(file_name("log-file-name")).into_inner(), vec![(2, AuditLogRole::Admin)]);
+	});
+}
+
+#[test]
+fn dont_renounce_ownership_if_sender_is_the_last_owner_or_not_an_owner() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Writer));
+
+		assert_noop!(Auditor::renounce_ownership(Origin::signed(1), "log-file-name".encode()), Error::<Test>::AuditLogMustKeepAnAdmin);
+		assert_noop!(Auditor::renounce_ownership(Origin::signed(2), "log-file-name".encode()), Error::<Test>::AccountIsNotAuditLogOwner);
+		assert_noop!(Auditor::renounce_ownership(Origin::signed(1), "unknown-file-name".encode()), Error::<Test>::AuditLogCantBeFound);
+	});
+}
+
+#[test]
+fn dont_save_audit_log_exceeding_the_length_limits() {
+	new_test_ext().execute_with(|| {
+		let long_file_name = vec![0u8; 33];
+		let long_content = vec![0u8; 65];
+		let title = "log-title".encode();
+		let timestamp = "2021-10-08 17:30:00 UTC".encode();
+
+		assert_noop!(Auditor::save_audit_log(Origin::signed(1), long_file_name, Some("2021-10-08".encode()), title.clone(), "content".encode(), timestamp.clone()), Error::<Test>::AuditLogFileNameTooLong);
+		assert_noop!(Auditor::save_audit_log(Origin::signed(1), "log-file-name".encode(), Some("2021-10-08".encode()), title, long_content, timestamp), Error::<Test>::AuditLogContentTooLong);
+	});
+}
+
+#[test]
+fn dont_save_more_audit_logs_than_allowed_per_day() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		save_default_audit_log(1);
+		save_default_audit_log(1);
+
+		let log_file_name = "log-file-name".encode();
+		let date = Some("2021-10-08".encode());
+		let title = "log-title".encode();
+		let content = "transaction with id 123 is processed".encode();
+		let timestamp = "2021-10-08 17:30:00 UTC".encode();
+		assert_noop!(Auditor::save_audit_log(Origin::signed(1), log_file_name, date, title, content, timestamp), Error::<Test>::TooManyAuditLogEntriesForDate);
+	});
+}
+
+#[test]
+fn dont_grant_more_roles_than_allowed_owners() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Writer));
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 3, AuditLogRole::Reader));
+
+		assert_noop!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 4, AuditLogRole::Reader), Error::<Test>::TooManyAuditLogOwners);
+		// Changing the role of an existing member is still possible
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 3, AuditLogRole::Auditor));
+	});
+}
+
+#[test]
+fn migrate_audit_logs_into_bounded_storage() {
+	use crate::migrations::v2::TRUNCATION_MARKER;
+	use frame_support::{storage::migration, Blake2_128Concat, StorageHasher};
+
+	new_test_ext().execute_with(|| {
+		let raw_file_name = "log-file-name".encode();
+		let raw_date = "2021-10-08".encode();
+		let key = [Blake2_128Concat::hash(&raw_file_name.encode()), Blake2_128Concat::hash(&raw_date.encode())].concat();
+		// Baseline audit logs as (title, content, reporter timestamp, reporter), more of them than allowed per day
+		let baseline_audit_logs = vec![
+			("log-title".encode(), "fits".encode(), "2021-10-08 17:30:00 UTC".encode(), 1u64),
+			("log-title".encode(), vec![0u8; 65], "2021-10-08 17:45:00 UTC".encode(), 1u64),
+			("log-title".encode(), "third".encode(), "2021-10-08 18:00:00 UTC".encode(), 1u64),
+			("log-title".encode(), "fourth".encode(), "2021-10-08 18:15:00 UTC".encode(), 1u64),
+		];
+		migration::put_storage_value(b"Auditor", b"AuditLogStorage", &key, baseline_audit_logs);
+
+		crate::migrations::migrate::<Test>();
+
+		// Every audit log is kept, the oversized content truncated with a marker
+		let audit_logs = Auditor::retrieve_audit_log(file_name("log-file-name"), 18_908);
+		assert_eq!(audit_logs.len(), 4);
+		assert!(audit_logs[0].clone().get_content().matches(&"fits".encode()));
+		let truncated_content = [vec![0u8; 64 - TRUNCATION_MARKER.len()], TRUNCATION_MARKER.to_vec()].concat();
+		assert!(audit_logs[1].clone().get_content().matches(&truncated_content));
+		assert!(Auditor::verify_chain("log-file-name".encode(), 0, 3));
+	});
+}
+
+#[test]
+fn migrate_audit_log_days_into_entries() {
+	use frame_support::{storage::migration, Blake2_128Concat, StorageHasher};
+
+	new_test_ext().execute_with(|| {
+		let raw_file_name = "log-file-name".encode();
+		let raw_date = "2021-10-08".encode();
+		let key = [Blake2_128Concat::hash(&raw_file_name.encode()), Blake2_128Concat::hash(&raw_date.encode())].concat();
+		let baseline_audit_logs = vec![
+			("log-title".encode(), "first".encode(), "2021-10-08 17:30:00 UTC".encode(), 1u64),
+			("log-title".encode(), "second".encode(), "2021-10-08 17:45:00 UTC".encode(), 1u64),
+		];
+		migration::put_storage_value(b"Auditor", b"AuditLogStorage", &key, baseline_audit_logs);
+
+		crate::migrations::migrate::<Test>();
+
+		assert_eq!(Auditor::retrieve_audit_log_entry_count(file_name("log-file-name"), 18_908), 2);
+		let second_entry = Auditor::retrieve_audit_log_entry((file_name("log-file-name"), 18_908, 1)).unwrap();
+		assert!(second_entry.get_content().matches(&"second".encode()));
+		assert_eq!(Auditor::retrieve_audit_logs("log-file-name".encode(), 18_908).len(), 2);
+		assert!(migration::get_storage_value::<Vec<u8>>(b"Auditor", b"AuditLogStorage", &key).is_none());
+		assert!(Auditor::verify_chain("log-file-name".encode(), 0, 1));
+	});
+}
+
+#[test]
+fn migrate_audit_log_dates_into_days() {
+	use crate::{merkle::verify_merkle_proof, AuditLogReporterDate};
+	use frame_support::{storage::migration, Blake2_128Concat, StorageHasher};
+	use sp_runtime::traits::BlakeTwo256;
+
+	new_test_ext().execute_with(|| {
+		let raw_file_name = "log-file-name".encode();
+		// A parsable date, then one only the on-chain timestamp of its audit log tells the day of,
+		// which is zero for the baseline audit logs
+		for raw_date in vec!["2021-10-08".encode(), "last tuesday".encode()] {
+			let key = [Blake2_128Concat::hash(&raw_file_name.encode()), Blake2_128Concat::hash(&raw_date.encode())].concat();
+			let baseline_audit_logs = vec![("log-title".encode(), "content".encode(), "2021-10-08 17:30:00 UTC".encode(), 1u64)];
+			migration::put_storage_value(b"Auditor", b"AuditLogStorage", &key, baseline_audit_logs);
+		}
+
+		crate::migrations::migrate::<Test>();
+
+		assert_eq!(Auditor::retrieve_audit_log_chain_location(file_name("log-file-name"), 0), Some((18_908, 0)));
+		assert_eq!(Auditor::retrieve_audit_log_chain_location(file_name("log-file-name"), 1), Some((0, 0)));
+		// The dates are kept as legacy claimed dates, which the chain hashes saved before do not commit to
+		for (log_day, raw_date) in vec![(18_908, "2021-10-08".encode()), (0, "last tuesday".encode())] {
+			let audit_log = Auditor::retrieve_audit_logs("log-file-name".encode(), log_day)[0].clone();
+			assert_eq!(audit_log.clone().get_reporter_date(), AuditLogReporterDate::Legacy(raw_date.clone().try_into().unwrap()));
+			assert_eq!(audit_log.get_claimed_date(), Some(raw_date));
+		}
+		assert!(Auditor::verify_chain("log-file-name".encode(), 0, 1));
+		for log_day in vec![18_908, 0] {
+			let (leaf, siblings, root) = Auditor::audit_log_entry_proof("log-file-name".encode(), log_day, 0).unwrap();
+			assert_eq!(root, Auditor::retrieve_audit_log_merkle_tree(file_name("log-file-name"), log_day).get_root());
+			assert!(verify_merkle_proof::<BlakeTwo256>(&root, &leaf, 0, &siblings));
+		}
+	});
+}
+
+#[test]
+fn parse_reporter_dates_into_days() {
+	use crate::migrations::v8::parse_day;
+
+	assert_eq!(parse_day(b"1970-01-01"), Some(0));
+	assert_eq!(parse_day(b"2021-10-08"), Some(18_908));
+	assert_eq!(parse_day(&"2021-10-08".encode()), Some(18_908));
+	assert_eq!(parse_day(b"2020-02-29T10:00:00Z"), Some(18_321));
+	assert_eq!(parse_day(b"2021-02-29"), None);
+	assert_eq!(parse_day(b"1969-12-31"), None);
+	assert_eq!(parse_day(b"2021-10-08x"), None);
+	assert_eq!(parse_day(b"08/10/2021"), None);
+}
+
+#[test]
+fn save_audit_log_hash_keeps_only_the_content_digest() {
+	use sp_runtime::traits::{BlakeTwo256, Hash};
+
+	new_test_ext().execute_with(|| {
+		let payload = "transaction with id 123 is processed".encode();
+		let digest = BlakeTwo256::hash(&payload);
+		assert_ok!(Auditor::save_audit_log_hash(Origin::signed(1), "log-file-name".encode(), Some("2021-10-08".encode()), "log-title".encode(), digest, payload.len() as u32, Some("s3://audit/123".encode()), "2021-10-08 17:30:00 UTC".encode()));
+
+		let audit_logs = Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY);
+		assert_eq!(audit_logs[0].clone().get_content(), AuditLogContent::Hashed { digest, length: payload.len() as u32, locator: Some("s3://audit/123".encode().try_into().unwrap()) });
+		assert!(Auditor::verify_audit_log_content("log-file-name".encode(), TODAY, 0, payload));
+		assert!(!Auditor::verify_audit_log_content("log-file-name".encode(), TODAY, 0, "tampered".encode()));
+	});
+}
+
+#[test]
+fn verify_audit_log_content_of_plain_audit_log() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+
+		assert!(Auditor::verify_audit_log_content("log-file-name".encode(), TODAY, 0, "transaction with id 123 is processed".encode()));
+		assert!(!Auditor::verify_audit_log_content("log-file-name".encode(), TODAY, 0, "tampered".encode()));
+		assert!(!Auditor::verify_audit_log_content("log-file-name".encode(), TODAY, 1, "transaction with id 123 is processed".encode()));
+	});
+}
+
+#[test]
+fn dont_save_audit_log_hash_with_a_too_long_locator() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Auditor::save_audit_log_hash(Origin::signed(1), "log-file-name".encode(), Some("2021-10-08".encode()), "log-title".encode(), Default::default(), 0, Some(vec![0u8; 33]), "2021-10-08 17:30:00 UTC".encode()), Error::<Test>::AuditLogLocatorTooLong);
+	});
+}
+
+// Saves an audit log with the given content on "log-file-name" for the date
+// Saves a log on "log-file-name" in a block of the given day
+fn save_audit_log_on_day(sender: u64, day: AuditLogDay, content: &str) {
+	Timestamp::set_timestamp(day as u64 * MILLISECS_PER_DAY);
+	assert_ok!(Auditor::save_audit_log(Origin::signed(sender), "log-file-name".encode(), None, "log-title".encode(), content.encode(), "2021-10-08 17:30:00 UTC".encode()));
+}
+
+#[test]
+fn audit_logs_of_a_log_file_are_chained_across_days() {
+	new_test_ext().execute_with(|| {
+		save_audit_log_on_day(1, TODAY, "first");
+		save_audit_log_on_day(1, TODAY, "second");
+		save_audit_log_on_day(1, TODAY + 1, "third");
+
+		let audit_logs = Auditor::retrieve_audit_log_chain("log-file-name".encode(), 0, 2);
+		assert_eq!(audit_logs.len(), 3);
+		assert_eq!(audit_logs[2].clone().get_sequence(), 2);
+		assert_eq!(audit_logs[2].clone().get_previous_hash(), audit_logs[1].chain_hash(&"log-file-name".encode()));
+		assert_eq!(Auditor::retrieve_audit_log_chain_head(file_name("log-file-name")).get_hash(), audit_logs[2].chain_hash(&"log-file-name".encode()));
+
+		assert!(Auditor::verify_chain("log-file-name".encode(), 0, 2));
+		assert!(Auditor::verify_chain("log-file-name".encode(), 1, 1));
+		assert!(!Auditor::verify_chain("log-file-name".encode(), 1, 3));
+		assert!(!Auditor::verify_chain("log-file-name".encode(), 2, 1));
+	});
+}
+
+#[test]
+fn exported_audit_log_chain_is_verified_offline() {
+	use crate::chain::{verify_audit_log_chain, AuditLogChainError};
+
+	new_test_ext().execute_with(|| {
+		save_audit_log_on_day(1, TODAY, "first");
+		save_audit_log_on_day(1, TODAY, "second");
+		save_audit_log_on_day(1, TODAY + 1, "third");
+		let log_file_name = "log-file-name".encode();
+		let head_hash = Auditor::retrieve_audit_log_chain_head(file_name("log-file-name")).get_hash();
+		let audit_logs = Auditor::retrieve_audit_log_chain(log_file_name.clone(), 0, 2);
+
+		assert_eq!(verify_audit_log_chain::<Test>(&log_file_name, 0, Default::default(), &audit_logs), Ok(head_hash));
+
+		// A missing audit log leaves a gap in the sequences
+		let with_gap = vec![audit_logs[0].clone(), audit_logs[2].clone()];
+		assert_eq!(verify_audit_log_chain::<Test>(&log_file_name, 0, Default::default(), &with_gap), Err(AuditLogChainError::SequenceGap(2)));
+
+		// An altered audit log no longer matches the previous hash of the one after it
+		let original = audit_logs[1].clone();
+		let altered = crate::AuditLog::new(
+			original.clone().get_title().try_into().unwrap(),
+			crate::AuditLogContent::Plain("altered".encode().try_into().unwrap()),
+			original.clone().get_reporter_timestamp().try_into().unwrap(),
+			original.clone().get_timestamp(),
+			original.clone().get_reporter(),
+			original.clone().get_sequence(),
+			original.get_previous_hash(),
+		);
+		let altered_logs = vec![audit_logs[0].clone(), altered, audit_logs[2].clone()];
+		assert_eq!(verify_audit_log_chain::<Test>(&log_file_name, 0, Default::default(), &altered_logs), Err(AuditLogChainError::BrokenLink(2)));
+	});
+}
+
+#[test]
+fn audit_log_entry_proof_verifies_against_the_merkle_root_of_the_day() {
+	use crate::merkle::verify_merkle_proof;
+	use sp_runtime::traits::BlakeTwo256;
+
+	new_test_ext().execute_with(|| {
+		save_audit_log_on_day(1, TODAY, "first");
+		save_audit_log_on_day(1, TODAY, "second");
+		save_audit_log_on_day(1, TODAY, "third");
+
+		let root = Auditor::retrieve_audit_log_merkle_tree(file_name("log-file-name"), TODAY).get_root();
+		for entry_index in 0..3 {
+			let (leaf, siblings, proof_root) = Auditor::audit_log_entry_proof("log-file-name".encode(), TODAY, entry_index).unwrap();
+			assert_eq!(proof_root, root);
+			assert!(verify_merkle_proof::<BlakeTwo256>(&root, &leaf, entry_index, &siblings));
+			assert!(!verify_merkle_proof::<BlakeTwo256>(&root, &leaf, entry_index + 1, &siblings));
+		}
+		assert!(Auditor::audit_log_entry_proof("log-file-name".encode(), TODAY, 3).is_none());
+	});
+}
+
+#[test]
+fn audit_log_entry_proof_is_none_once_an_audit_log_of_the_day_is_gone() {
+	new_test_ext().execute_with(|| {
+		save_audit_log_on_day(1, TODAY, "first");
+		save_audit_log_on_day(1, TODAY, "second");
+		save_audit_log_on_day(1, TODAY, "third");
+
+		// Partially pruned day, the leaves left no longer add up to the root of the day
+		crate::AuditLogEntryStorage::<Test>::remove((file_name("log-file-name"), TODAY, 0));
+
+		for entry_index in 0..3 {
+			assert!(Auditor::audit_log_entry_proof("log-file-name".encode(), TODAY, entry_index).is_none());
+		}
+	});
+}
+
+// Builds a batch entry for the log file
+fn batch_entry(log_file_name: &str, content: &str) -> AuditLogBatchEntry {
+	AuditLogBatchEntry::new(log_file_name.encode(), Some("2021-10-08".encode()), "log-title".encode(), content.encode(), "2021-10-08 17:30:00 UTC".encode(), Default::default())
+}
+
+#[test]
+fn save_audit_logs_batch_across_log_files() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let audit_logs = vec![
+			batch_entry("log-file-name", "first"),
+			batch_entry("log-file-name", "second"),
+			batch_entry("other-log-file-name", "third"),
+		];
+
+		assert_ok!(Auditor::save_audit_logs_batch(Origin::signed(1), audit_logs));
+
+		System::assert_has_event(Event::Auditor(crate::Event::AuditLogInformationStored(file_name("other-log-file-name"), TODAY, 1, Severity::Info, Default::default(), Default::default())));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogBatchStored(1, 3)));
+		assert_eq!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY).len(), 2);
+		assert_eq!(Auditor::retrieve_audit_log_owner(file_name("other-log-file-name")), vec![1]);
+		assert!(Auditor::verify_chain("log-file-name".encode(), 0, 1));
+	});
+}
+
+#[test]
+fn save_audit_logs_batch_saves_nothing_if_an_audit_log_is_rejected() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(2);
+		let audit_logs = vec![
+			batch_entry("own-log-file-name", "first"),
+			batch_entry("log-file-name", "second"),
+		];
+
+		assert_noop!(Auditor::save_audit_logs_batch(Origin::signed(1), audit_logs), Error::<Test>::AuditLogIdentifierCannotBeUsed);
+	});
+}
+
+#[test]
+fn dont_save_empty_or_oversized_audit_logs_batch() {
+	new_test_ext().execute_with(|| {
+		let audit_logs = (0..5).map(|_| batch_entry("log-file-name", "content")).collect::<Vec<_>>();
+
+		assert_noop!(Auditor::save_audit_logs_batch(Origin::signed(1), Vec::new()), Error::<Test>::EmptyAuditLogBatch);
+		assert_noop!(Auditor::save_audit_logs_batch(Origin::signed(1), audit_logs), Error::<Test>::TooManyAuditLogsInBatch);
+	});
+}
+
+// Saves a structured audit log on "log-file-name", claimed to be of "2021-10-08"
+fn save_structured_audit_log(sender: u64, severity: Severity, tags: Vec<&str>, attributes: Vec<(&str, &str)>) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	let metadata = AuditLogMetadata::new(
+		severity,
+		tags.into_iter().map(|tag| tag.encode()).collect(),
+		attributes.into_iter().map(|(key, value)| (key.encode(), value.encode())).collect(),
+	);
+	Auditor::save_structured_audit_log(Origin::signed(sender), "log-file-name".encode(), Some("2021-10-08".encode()), "log-title".encode(), "content".encode(), "2021-10-08 17:30:00 UTC".encode(), metadata)
+}
+
+#[test]
+fn save_structured_audit_log_with_severity_tags_and_attributes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(save_structured_audit_log(1, Severity::Warning, vec!["billing"], vec![("customer", "42")]));
+
+		let tags: crate::AuditLogTags<Test> = vec!["billing".encode().try_into().unwrap()].try_into().unwrap();
+		let attributes: crate::AuditLogAttributes<Test> = vec![("customer".encode().try_into().unwrap(), "42".encode().try_into().unwrap())].try_into().unwrap();
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogInformationStored(file_name("log-file-name"), TODAY, 1, Severity::Warning, tags.clone(), attributes.clone())));
+
+		let audit_log = Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY)[0].clone();
+		assert_eq!(audit_log.clone().get_severity(), Severity::Warning);
+		assert_eq!(audit_log.clone().get_tags(), tags);
+		assert_eq!(audit_log.get_attributes(), attributes);
+		assert!(Auditor::verify_chain("log-file-name".encode(), 0, 0));
+	});
+}
+
+#[test]
+fn retrieve_audit_logs_filtered_by_severity_tag_and_attribute() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(save_structured_audit_log(1, Severity::Debug, vec!["billing"], vec![("customer", "42")]));
+		assert_ok!(save_structured_audit_log(1, Severity::Error, vec!["billing"], vec![("customer", "7")]));
+		assert_ok!(save_structured_audit_log(1, Severity::Critical, vec!["shipping"], vec![("customer", "42")]));
+		let filtered = |min_severity: Option<Severity>, tag: Option<&str>, attribute: Option<(&str, &str)>| {
+			Auditor::retrieve_audit_logs_filtered(
+				"log-file-name".encode(),
+				TODAY,
+				min_severity,
+				tag.map(|tag| tag.encode()),
+				attribute.map(|(key, value)| (key.encode(), value.encode())),
+			).len()
+		};
+
+		assert_eq!(filtered(None, None, None), 3);
+		assert_eq!(filtered(Some(Severity::Error), None, None), 2);
+		assert_eq!(filtered(None, Some("billing"), None), 2);
+		assert_eq!(filtered(None, None, Some(("customer", "42"))), 2);
+		assert_eq!(filtered(Some(Severity::Warning), Some("billing"), Some(("customer", "7"))), 1);
+	});
+}
+
+#[test]
+fn dont_save_structured_audit_log_exceeding_the_metadata_limits() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(save_structured_audit_log(1, Severity::Info, vec!["a-tag-way-too-long-to-fit"], vec![]), Error::<Test>::AuditLogTagTooLong);
+		assert_noop!(save_structured_audit_log(1, Severity::Info, vec!["a", "b", "c", "d", "e"], vec![]), Error::<Test>::TooManyAuditLogTags);
+		assert_noop!(save_structured_audit_log(1, Severity::Info, vec![], vec![("a-key-way-too-long-to-fit", "value")]), Error::<Test>::AuditLogAttributeKeyTooLong);
+	});
+}
+
+// Sets a schema on "log-file-name" requiring a "customer" attribute and at most 16 bytes of content
+fn set_default_log_schema(sender: u64, allowed_severities: Vec<Severity>, content_type: AuditLogContentType) -> frame_support::dispatch::DispatchResult {
+	Auditor::set_log_schema(Origin::signed(sender), "log-file-name".encode(), vec!["customer".encode()], allowed_severities, Some(16), content_type)
+}
+
+#[test]
+fn save_audit_log_matching_the_log_schema() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		System::set_block_number(1);
+		assert_ok!(set_default_log_schema(1, vec![Severity::Info, Severity::Warning], AuditLogContentType::Utf8));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogSchemaSet(file_name("log-file-name"), 1)));
+
+		assert_ok!(save_structured_audit_log(1, Severity::Warning, vec![], vec![("customer", "42")]));
+		assert!(Auditor::retrieve_active_log_schema("log-file-name".encode()).is_some());
+	});
+}
+
+#[test]
+fn dont_save_audit_log_not_matching_the_log_schema() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(set_default_log_schema(1, vec![Severity::Info], AuditLogContentType::OnChain));
+
+		assert_noop!(save_structured_audit_log(1, Severity::Info, vec![], vec![]), Error::<Test>::AuditLogMissingRequiredAttribute);
+		assert_noop!(save_structured_audit_log(1, Severity::Error, vec![], vec![("customer", "42")]), Error::<Test>::AuditLogSeverityNotAllowedBySchema);
+		assert_noop!(Auditor::save_audit_log(Origin::signed(1), "log-file-name".encode(), Some("2021-10-08".encode()), "log-title".encode(), vec![0u8; 17], "2021-10-08 17:30:00 UTC".encode()), Error::<Test>::AuditLogMissingRequiredAttribute);
+		assert_noop!(
+			Auditor::save_audit_logs_batch(Origin::signed(1), vec![batch_entry("log-file-name", "content")]),
+			Error::<Test>::AuditLogMissingRequiredAttribute
+		);
+	});
+}
+
+#[test]
+fn dont_save_audit_log_with_content_not_allowed_by_the_log_schema() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::set_log_schema(Origin::signed(1), "log-file-name".encode(), vec![], vec![], Some(16), AuditLogContentType::OffChain));
+
+		assert_noop!(Auditor::save_audit_log(Origin::signed(1), "log-file-name".encode(), Some("2021-10-08".encode()), "log-title".encode(), "content".encode(), "2021-10-08 17:30:00 UTC".encode()), Error::<Test>::AuditLogContentTypeNotAllowedBySchema);
+		assert_noop!(Auditor::save_audit_log_hash(Origin::signed(1), "log-file-name".encode(), Some("2021-10-08".encode()), "log-title".encode(), Default::default(), 17, None, "2021-10-08 17:30:00 UTC".encode()), Error::<Test>::AuditLogContentTooLongForSchema);
+		assert_ok!(Auditor::save_audit_log_hash(Origin::signed(1), "log-file-name".encode(), Some("2021-10-08".encode()), "log-title".encode(), Default::default(), 16, None, "2021-10-08 17:30:00 UTC".encode()));
+	});
+}
+
+#[test]
+fn clear_log_schema_lets_any_audit_log_be_saved_again() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(set_default_log_schema(1, vec![], AuditLogContentType::Any));
+
+		assert_noop!(set_default_log_schema(2, vec![], AuditLogContentType::Any), Error::<Test>::NoRightsToManageAuditLogRoles);
+		assert_noop!(Auditor::clear_log_schema(Origin::signed(2), "log-file-name".encode()), Error::<Test>::NoRightsToManageAuditLogRoles);
+		assert_ok!(Auditor::clear_log_schema(Origin::signed(1), "log-file-name".encode()));
+		assert_noop!(Auditor::clear_log_schema(Origin::signed(1), "log-file-name".encode()), Error::<Test>::AuditLogSchemaCantBeFound);
+		save_default_audit_log(1);
+	});
+}
+
+#[test]
+fn save_audit_log_reserves_a_deposit_from_the_writer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+
+		let audit_log = Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY)[0].clone();
+		let deposit = Auditor::audit_log_deposit(&audit_log);
+		assert_eq!(deposit, 10 + audit_log.encoded_size() as u64);
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		assert_eq!(Auditor::retrieve_audit_log_deposit((file_name("log-file-name"), TODAY, 1)), deposit);
+		System::assert_has_event(Event::Auditor(crate::Event::AuditLogDepositReserved(file_name("log-file-name"), 1, deposit)));
+
+		// The account 4 has no balance to reserve the deposit from
+		assert_noop!(
+			Auditor::save_audit_log(Origin::signed(4), "other-log-file-name".encode(), None, "log-title".encode(), "content".encode(), "2021-10-08 17:30:00 UTC".encode()),
+			Error::<Test>::InsufficientBalanceForDeposit
+		);
+	});
+}
+
+#[test]
+fn delete_log_file_gives_the_deposits_back() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Writer));
+		save_audit_log_on_day(2, TODAY, "first");
+		save_audit_log_on_day(2, TODAY + 1, "second");
+		let deposit_of_2 = Balances::reserved_balance(2);
+
+		assert_noop!(Auditor::delete_log_file(Origin::signed(2), "log-file-name".encode()), Error::<Test>::NoRightsToManageAuditLogRoles);
+		assert_ok!(Auditor::delete_log_file(Origin::signed(1), "log-file-name".encode()));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogFileDeleted(file_name("log-file-name"), 1)));
+		assert_eq!(Auditor::retrieve_audit_log_chain_head(file_name("log-file-name")).get_length(), 0);
+
+		// The audit logs are removed on idle, the name can't be used until nothing remains of them
+		assert_eq!(Balances::reserved_balance(2), deposit_of_2);
+		assert_noop!(
+			Auditor::save_audit_log(Origin::signed(2), "log-file-name".encode(), None, "log-title".encode(), "content".encode(), "2021-10-08 17:30:00 UTC".encode()),
+			Error::<Test>::LogFileBeingDeleted
+		);
+		Auditor::on_idle(1, 2);
+		assert!(crate::AuditLogDeletedFileStorage::<Test>::contains_key(file_name("log-file-name")));
+		Auditor::on_idle(2, Weight::max_value());
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogFileCleared(file_name("log-file-name"))));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE);
+		assert!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY).is_empty());
+		assert!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY + 1).is_empty());
+		save_audit_log_on_day(2, TODAY, "third");
+
+		// The log file name can be used again, by anyone
+		save_default_audit_log(3);
+		assert!(Auditor::verify_chain("log-file-name".encode(), 0, 0));
+	});
+}
+
+#[test]
+fn set_log_retention_schedules_the_days_kept() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+
+		assert_noop!(Auditor::set_log_retention(Origin::signed(2), "log-file-name".encode(), Some(2), 1), Error::<Test>::NoRightsToManageAuditLogRoles);
+		assert_noop!(Auditor::set_log_retention(Origin::signed(1), "log-file-name".encode(), Some(0), 1), Error::<Test>::InvalidRetentionPeriod);
+		// Each day kept is rescheduled, the admin being charged for as many as it says there are
+		assert_eq!(Auditor::log_day_count("log-file-name".encode()), 1);
+		assert_noop!(Auditor::set_log_retention(Origin::signed(1), "log-file-name".encode(), Some(2), 0), Error::<Test>::LogDayCountTooLow);
+		assert_ok!(Auditor::set_log_retention(Origin::signed(1), "log-file-name".encode(), Some(2), 1));
+
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogRetentionSet(file_name("log-file-name"), 1, Some(2))));
+		assert_eq!(Auditor::retrieve_log_retention(file_name("log-file-name")), Some(2));
+		assert!(Auditor::audit_logs_due_for_pruning(1).is_empty());
+		assert_eq!(Auditor::audit_logs_due_for_pruning(2), vec![("log-file-name".encode(), TODAY, TODAY + 2)]);
+
+		// Keeping the audit logs forever again takes them off the schedule
+		assert_ok!(Auditor::set_log_retention(Origin::signed(1), "log-file-name".encode(), None, 1));
+		assert_eq!(Auditor::retrieve_log_retention(file_name("log-file-name")), None);
+		assert!(Auditor::audit_logs_due_for_pruning(2).is_empty());
+	});
+}
+
+#[test]
+fn audit_logs_over_the_retention_period_are_pruned_on_idle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::set_log_retention(Origin::signed(1), "log-file-name".encode(), Some(2), 1));
+		let deposit_of_today = Balances::reserved_balance(1);
+		save_audit_log_on_day(1, TODAY + 1, "second");
+
+		// Nothing is due before the retention period is over
+		Auditor::on_idle(1, Weight::max_value());
+		assert_eq!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY).len(), 1);
+
+		Timestamp::set_timestamp((TODAY as u64 + 2) * MILLISECS_PER_DAY);
+		Auditor::on_idle(2, Weight::max_value());
+
+		System::assert_has_event(Event::Auditor(crate::Event::AuditLogDepositUnreserved(file_name("log-file-name"), 1, deposit_of_today)));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogsPruned(file_name("log-file-name"), TODAY, 1)));
+		assert!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY).is_empty());
+		assert_eq!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY + 1).len(), 1);
+		assert_eq!(Auditor::retrieve_audit_log_deposit((file_name("log-file-name"), TODAY, 1)), 0);
+		assert_eq!(Auditor::audit_logs_due_for_pruning(1), vec![("log-file-name".encode(), TODAY + 1, TODAY + 3)]);
+
+		// The chain keeps going from the audit logs left
+		assert_eq!(Auditor::retrieve_audit_log_chain_head(file_name("log-file-name")).get_length(), 2);
+		assert!(Auditor::verify_chain("log-file-name".encode(), 1, 1));
+	});
+}
+
+#[test]
+fn legal_hold_is_placed_and_released_by_the_legal_hold_origin_only() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		let reason_hash = sp_core::H256::repeat_byte(7);
+
+		assert_noop!(Auditor::place_legal_hold(Origin::signed(1), "log-file-name".encode(), reason_hash), sp_runtime::traits::BadOrigin);
+		assert_noop!(Auditor::release_legal_hold(Origin::root(), "log-file-name".encode(), 1), Error::<Test>::LogFileNotUnderLegalHold);
+		assert_ok!(Auditor::place_legal_hold(Origin::root(), "log-file-name".encode(), reason_hash));
+		System::assert_last_event(Event::Auditor(crate::Event::LegalHoldPlaced(file_name("log-file-name"), reason_hash)));
+		assert_eq!(Auditor::retrieve_legal_hold(file_name("log-file-name")), Some(reason_hash));
+		assert_noop!(Auditor::place_legal_hold(Origin::root(), "log-file-name".encode(), reason_hash), Error::<Test>::LogFileUnderLegalHold);
+
+		// Not even the owner of the log file can delete it while on hold
+		assert_noop!(Auditor::delete_log_file(Origin::signed(1), "log-file-name".encode()), Error::<Test>::LogFileUnderLegalHold);
+
+		assert_noop!(Auditor::release_legal_hold(Origin::signed(1), "log-file-name".encode(), 1), sp_runtime::traits::BadOrigin);
+		assert_noop!(Auditor::release_legal_hold(Origin::root(), "log-file-name".encode(), 0), Error::<Test>::LogDayCountTooLow);
+		assert_ok!(Auditor::release_legal_hold(Origin::root(), "log-file-name".encode(), 1));
+		System::assert_last_event(Event::Auditor(crate::Event::LegalHoldReleased(file_name("log-file-name"))));
+		assert_ok!(Auditor::delete_log_file(Origin::signed(1), "log-file-name".encode()));
+	});
+}
+
+#[test]
+fn dont_place_legal_hold_on_a_log_file_that_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let reason_hash = sp_core::H256::repeat_byte(7);
+
+		assert_noop!(Auditor::place_legal_hold(Origin::root(), "log-file-name".encode(), reason_hash), Error::<Test>::AuditLogCantBeFound);
+
+		// Nor on one that has been deleted
+		save_default_audit_log(1);
+		assert_ok!(Auditor::delete_log_file(Origin::signed(1), "log-file-name".encode()));
+		assert_noop!(Auditor::place_legal_hold(Origin::root(), "log-file-name".encode(), reason_hash), Error::<Test>::AuditLogCantBeFound);
+		assert_eq!(Auditor::retrieve_legal_hold(file_name("log-file-name")), None);
+	});
+}
+
+#[test]
+fn audit_logs_under_legal_hold_are_not_pruned_until_released() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::set_log_retention(Origin::signed(1), "log-file-name".encode(), Some(1), 1));
+		assert_ok!(Auditor::place_legal_hold(Origin::root(), "log-file-name".encode(), sp_core::H256::repeat_byte(7)));
+
+		Timestamp::set_timestamp((TODAY as u64 + 2) * MILLISECS_PER_DAY);
+		Auditor::on_idle(1, Weight::max_value());
+		assert_eq!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY).len(), 1);
+		assert!(Auditor::audit_logs_due_for_pruning(0).is_empty());
+
+		// The audit logs over the retention period are due as soon as the hold is released
+		assert_ok!(Auditor::release_legal_hold(Origin::root(), "log-file-name".encode(), 1));
+		assert_eq!(Auditor::audit_logs_due_for_pruning(0), vec![("log-file-name".encode(), TODAY, TODAY + 2)]);
+		Auditor::on_idle(2, Weight::max_value());
+		assert!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY).is_empty());
+	});
+}
+
+#[test]
+fn redact_audit_log_entry_keeps_the_chain_and_merkle_root_valid() {
+	use crate::merkle::verify_merkle_proof;
+	use sp_runtime::traits::BlakeTwo256;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		save_default_audit_log(1);
+		let merkle_root = Auditor::retrieve_audit_log_merkle_tree(file_name("log-file-name"), TODAY).get_root();
+		let reason_hash = sp_core::H256::repeat_byte(3);
+
+		assert_noop!(Auditor::redact_audit_log_entry(Origin::signed(2), "log-file-name".encode(), TODAY, 0, reason_hash), Error::<Test>::NoRightsToManageAuditLogRoles);
+		assert_noop!(Auditor::redact_audit_log_entry(Origin::signed(1), "log-file-name".encode(), TODAY, 2, reason_hash), Error::<Test>::AuditLogCantBeFound);
+		Timestamp::set_timestamp(5);
+		assert_ok!(Auditor::redact_audit_log_entry(Origin::signed(1), "log-file-name".encode(), TODAY, 0, reason_hash));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogRedacted(file_name("log-file-name"), TODAY, 0, Some(1), reason_hash)));
+		assert_noop!(Auditor::redact_audit_log_entry(Origin::signed(1), "log-file-name".encode(), TODAY, 0, reason_hash), Error::<Test>::AuditLogAlreadyRedacted);
+
+		match Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY)[0].clone().get_content() {
+			AuditLogContent::Redacted { redacted_by, redacted_at, reason_hash: redaction_reason_hash, length, .. } => {
+				assert_eq!((redacted_by, redacted_at, redaction_reason_hash), (Some(1), 5, reason_hash));
+				assert_eq!(length as usize, "transaction with id 123 is processed".encode().len());
+			},
+			content => panic!("content is not redacted: {:?}", content),
+		}
+
+		// The redacted content is gone, yet it can still be proven to be what was saved
+		assert!(Auditor::verify_audit_log_content("log-file-name".encode(), TODAY, 0, "transaction with id 123 is processed".encode()));
+		assert!(Auditor::verify_chain("log-file-name".encode(), 0, 1));
+		let (leaf, siblings, root) = Auditor::audit_log_entry_proof("log-file-name".encode(), TODAY, 0).unwrap();
+		assert_eq!(root, merkle_root);
+		assert!(verify_merkle_proof::<BlakeTwo256>(&root, &leaf, 0, &siblings));
+	});
+}
+
+#[test]
+fn redact_audit_log_entry_by_the_privacy_officer_origin_unless_under_legal_hold() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		let reason_hash = sp_core::H256::repeat_byte(3);
+		assert_ok!(Auditor::place_legal_hold(Origin::root(), "log-file-name".encode(), reason_hash));
+
+		assert_noop!(Auditor::redact_audit_log_entry(Origin::root(), "log-file-name".encode(), TODAY, 0, reason_hash), Error::<Test>::LogFileUnderLegalHold);
+		assert_noop!(Auditor::redact_audit_log_entry(Origin::signed(1), "log-file-name".encode(), TODAY, 0, reason_hash), Error::<Test>::LogFileUnderLegalHold);
+
+		assert_ok!(Auditor::release_legal_hold(Origin::root(), "log-file-name".encode(), 1));
+		assert_ok!(Auditor::redact_audit_log_entry(Origin::root(), "log-file-name".encode(), TODAY, 0, reason_hash));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogRedacted(file_name("log-file-name"), TODAY, 0, None, reason_hash)));
+	});
+}
+
+// Saves an audit log encrypted for the readers, every one of them given the same sealed content key
+fn save_encrypted_audit_log(sender: u64, readers: Vec<u64>) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	let wrapped_keys = readers.into_iter().map(|reader| (reader, [reader as u8; 48])).collect();
+	Auditor::save_encrypted_audit_log(Origin::signed(sender), "log-file-name".encode(), None, "log-title".encode(), "ciphertext".encode(), [1; 12], [2; 32], wrapped_keys, "2021-10-08 17:30:00 UTC".encode())
+}
+
+#[test]
+fn save_encrypted_audit_log_for_readers_with_an_encryption_key() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Auditor::register_encryption_key(Origin::signed(2), [2; 32]));
+		System::assert_last_event(Event::Auditor(crate::Event::EncryptionKeyRegistered(2, [2; 32])));
+		assert_ok!(Auditor::register_encryption_key(Origin::signed(3), [3; 32]));
+		assert_eq!(Auditor::retrieve_encryption_key(3), Some([3; 32]));
+
+		assert_noop!(save_encrypted_audit_log(1, vec![]), Error::<Test>::NoReaders);
+		assert_noop!(save_encrypted_audit_log(1, vec![2, 4]), Error::<Test>::ReaderHasNoEncryptionKey);
+		assert_noop!(save_encrypted_audit_log(1, vec![2, 3, 2]), Error::<Test>::DuplicateReader);
+		assert_noop!(save_encrypted_audit_log(1, vec![2, 3, 2, 3]), Error::<Test>::TooManyReaders);
+		assert_ok!(save_encrypted_audit_log(1, vec![2, 3]));
+
+		match Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY)[0].clone().get_content() {
+			AuditLogContent::Encrypted(encrypted) => {
+				assert_eq!(encrypted.wrapped_key_of(&3), Some([3; 48]));
+				assert_eq!(encrypted.wrapped_key_of(&1), None);
+				assert_eq!(encrypted.get_ciphertext(), "ciphertext".encode());
+			},
+			content => panic!("content is not encrypted: {:?}", content),
+		}
+		assert!(Auditor::verify_audit_log_content("log-file-name".encode(), TODAY, 0, "ciphertext".encode()));
+		assert!(Auditor::verify_chain("log-file-name".encode(), 0, 0));
+	});
+}
+
+#[test]
+fn log_schema_can_require_encrypted_content() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::register_encryption_key(Origin::signed(2), [2; 32]));
+		assert_ok!(Auditor::set_log_schema(Origin::signed(1), "log-file-name".encode(), vec![], vec![], None, AuditLogContentType::Encrypted));
+
+		assert_noop!(
+			Auditor::save_audit_log(Origin::signed(1), "log-file-name".encode(), None, "log-title".encode(), "plaintext".encode(), "2021-10-08 17:30:00 UTC".encode()),
+			Error::<Test>::AuditLogContentTypeNotAllowedBySchema
+		);
+		assert_ok!(save_encrypted_audit_log(1, vec![2]));
+	});
+}
+
+#[test]
+fn start_key_epoch_seals_new_audit_logs_for_its_readers_only() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::register_encryption_key(Origin::signed(2), [2; 32]));
+		assert_ok!(Auditor::register_encryption_key(Origin::signed(3), [3; 32]));
+
+		// Before the first key epoch, audit logs are sealed in epoch zero
+		assert_ok!(save_encrypted_audit_log(1, vec![2, 3]));
+		System::assert_last_event(Event::Auditor(crate::Event::EncryptedAuditLogStored(file_name("log-file-name"), TODAY, 1, 0)));
+
+		assert_noop!(Auditor::start_key_epoch(Origin::signed(2), "log-file-name".encode(), vec![2, 3]), Error::<Test>::NoRightsToManageAuditLogRoles);
+		assert_noop!(Auditor::start_key_epoch(Origin::signed(1), "log-file-name".encode(), vec![2, 4]), Error::<Test>::ReaderHasNoEncryptionKey);
+		assert_noop!(Auditor::start_key_epoch(Origin::signed(1), "log-file-name".encode(), vec![]), Error::<Test>::NoReaders);
+		assert_ok!(Auditor::start_key_epoch(Origin::signed(1), "log-file-name".encode(), vec![2, 3]));
+		System::assert_last_event(Event::Auditor(crate::Event::KeyEpochStarted(
+			file_name("log-file-name"),
+			1,
+			vec![(2, [2; 32]), (3, [3; 32])].try_into().unwrap(),
+		)));
+
+		// Reader 2 rotates its key then is removed from the readers of the next key epoch
+		assert_ok!(Auditor::register_encryption_key(Origin::signed(2), [22; 32]));
+		assert_ok!(save_encrypted_audit_log(1, vec![3, 2]));
+		System::assert_last_event(Event::Auditor(crate::Event::EncryptedAuditLogStored(file_name("log-file-name"), TODAY, 2, 1)));
+		assert_ok!(Auditor::start_key_epoch(Origin::signed(1), "log-file-name".encode(), vec![3]));
+		assert_eq!(Auditor::retrieve_key_epoch(file_name("log-file-name")).unwrap().get_epoch(), 2);
+
+		assert_noop!(save_encrypted_audit_log(1, vec![2, 3]), Error::<Test>::ReadersNotInKeyEpoch);
+		assert_ok!(save_encrypted_audit_log(1, vec![3]));
+		System::assert_last_event(Event::Auditor(crate::Event::EncryptedAuditLogStored(file_name("log-file-name"), TODAY, 3, 2)));
+
+		let key_epochs = Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY)
+			.into_iter()
+			.filter_map(|audit_log| match audit_log.get_content() {
+				AuditLogContent::Encrypted(encrypted) => Some(encrypted.get_key_epoch()),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(key_epochs, vec![0, 1, 2]);
+	});
+}
+
+#[test]
+fn migrate_encrypted_audit_logs_into_key_epoch_zero() {
+	use frame_support::{storage::migration, Blake2_128Concat, StorageHasher, Twox64Concat};
+
+	new_test_ext().execute_with(|| {
+		// Encrypted content at version 8, variant 3 without a key epoch
+		let content = (3u8, "ciphertext".encode(), [1u8; 12], [2u8; 32], vec![(2u64, [2u8; 48])]);
+		let old_audit_log = ("log-title".encode(), content, "2021-10-08 17:30:00 UTC".encode(), 0u64, 1u64, 0u64, sp_core::H256::default(), 1u8, Vec::<Vec<u8>>::new(), Vec::<(Vec<u8>, Vec<u8>)>::new(), None::<Vec<u8>>);
+		let key = [Blake2_128Concat::hash(&"log-file-name".encode().encode()), Twox64Concat::hash(&TODAY.encode()), Twox64Concat::hash(&0u32.encode())].concat();
+		migration::put_storage_value(b"Auditor", b"AuditLogEntryStorage", &key, old_audit_log);
+		frame_support::traits::StorageVersion::new(8).put::<Auditor>();
+
+		crate::migrations::migrate::<Test>();
+
+		match Auditor::retrieve_audit_log_entry((file_name("log-file-name"), TODAY, 0)).unwrap().get_content() {
+			AuditLogContent::Encrypted(encrypted) => {
+				assert_eq!(encrypted.wrapped_key_of(&2), Some([2; 48]));
+				assert_eq!(encrypted.get_key_epoch(), 0);
+			},
+			content => panic!("content is not encrypted: {:?}", content),
+		}
+	});
+}
+
+// Registers the sender as the reporter of the payments api in production
+fn register_payments_reporter(sender: u64) -> frame_support::dispatch::DispatchResult {
+	Auditor::register_reporter(Origin::signed(sender), b"payments-api".to_vec(), b"prod".to_vec(), b"1.4.2".to_vec(), sp_core::H256::repeat_byte(9))
+}
+
+#[test]
+fn register_update_and_retire_reporter() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(register_payments_reporter(1));
+		System::assert_last_event(Event::Auditor(crate::Event::ReporterRegistered(1)));
+		assert_noop!(register_payments_reporter(1), Error::<Test>::ReporterAlreadyRegistered);
+		assert_eq!(Auditor::reporter_label(1), Some(b"payments-api (prod)".to_vec()));
+		assert_eq!(Auditor::reporter_label(2), None);
+
+		assert_noop!(
+			Auditor::register_reporter(Origin::signed(2), b"a-system-name-too-long".to_vec(), b"prod".to_vec(), b"1.0.0".to_vec(), sp_core::H256::default()),
+			Error::<Test>::ReporterFieldTooLong
+		);
+		assert_noop!(
+			Auditor::update_reporter(Origin::signed(2), b"payments-api".to_vec(), b"staging".to_vec(), b"1.5.0".to_vec(), sp_core::H256::default()),
+			Error::<Test>::ReporterNotRegistered
+		);
+		assert_ok!(Auditor::update_reporter(Origin::signed(1), b"payments-api".to_vec(), b"staging".to_vec(), b"1.5.0".to_vec(), sp_core::H256::default()));
+		System::assert_last_event(Event::Auditor(crate::Event::ReporterUpdated(1)));
+		let reporter = Auditor::retrieve_reporter(1).unwrap();
+		assert_eq!((reporter.clone().get_account(), reporter.clone().get_software_version()), (1, b"1.5.0".to_vec()));
+
+		assert_ok!(Auditor::retire_reporter(Origin::signed(1)));
+		System::assert_last_event(Event::Auditor(crate::Event::ReporterRetired(1)));
+		assert!(!Auditor::retrieve_reporter(1).unwrap().is_active());
+		assert_noop!(Auditor::retire_reporter(Origin::signed(1)), Error::<Test>::ReporterIsRetired);
+		// Audit logs saved before keep showing who saved them
+		assert_eq!(Auditor::reporter_label(1), Some(b"payments-api (staging)".to_vec()));
+	});
+}
+
+// Saves an audit log on the default log file, returning whether it has been saved
+fn save_audit_log_with_sender(sender: u64) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	Auditor::save_audit_log(Origin::signed(sender), "log-file-name".encode(), None, "log-title".encode(), "content".encode(), "2021-10-08 17:30:00 UTC".encode())
+}
+
+#[test]
+fn log_file_accepting_registered_reporters_only() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Writer));
+
+		assert_noop!(Auditor::set_registered_reporters_only(Origin::signed(2), "log-file-name".encode(), true), Error::<Test>::NoRightsToManageAuditLogRoles);
+		assert_ok!(Auditor::set_registered_reporters_only(Origin::signed(1), "log-file-name".encode(), true));
+		System::assert_last_event(Event::Auditor(crate::Event::RegisteredReportersOnlySet(file_name("log-file-name"), 1, true)));
+		assert!(Auditor::accepts_registered_reporters_only(file_name("log-file-name")));
+
+		assert_noop!(save_audit_log_with_sender(2), Error::<Test>::ReporterNotRegistered);
+		assert_ok!(register_payments_reporter(2));
+		assert_ok!(save_audit_log_with_sender(2));
+		assert_ok!(Auditor::retire_reporter(Origin::signed(2)));
+		assert_noop!(save_audit_log_with_sender(2), Error::<Test>::ReporterIsRetired);
+
+		assert_ok!(Auditor::set_registered_reporters_only(Origin::signed(1), "log-file-name".encode(), false));
+		assert_ok!(save_audit_log_with_sender(2));
+	});
+}
+
+#[test]
+fn delegate_saves_audit_logs_within_its_daily_quota() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_noop!(save_audit_log_with_sender(2), Error::<Test>::AuditLogIdentifierCannotBeUsed);
+
+		assert_noop!(Auditor::authorize_delegate(Origin::signed(2), "log-file-name".encode(), 2, None, Some(2)), Error::<Test>::NoRightsToManageAuditLogRoles);
+		assert_ok!(Auditor::authorize_delegate(Origin::signed(1), "log-file-name".encode(), 2, None, Some(2)));
+		System::assert_last_event(Event::Auditor(crate::Event::DelegateAuthorized(file_name("log-file-name"), 2, None, Some(2))));
+
+		assert_ok!(save_audit_log_with_sender(2));
+		assert_ok!(save_audit_log_with_sender(2));
+		assert_noop!(save_audit_log_with_sender(2), Error::<Test>::DelegateQuotaExceeded);
+
+		// The quota is counted again on the next day
+		Timestamp::set_timestamp(MILLISECS_PER_DAY);
+		assert_ok!(save_audit_log_with_sender(2));
+		assert_eq!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY + 1).len(), 1);
+	});
+}
+
+#[test]
+fn delegation_expires_and_can_be_revoked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::authorize_delegate(Origin::signed(1), "log-file-name".encode(), 2, Some(5), None));
+		assert_ok!(Auditor::authorize_delegate(Origin::signed(1), "log-file-name".encode(), 3, None, None));
+		assert_eq!(Auditor::retrieve_delegation(file_name("log-file-name"), 2).unwrap().get_expires_at(), Some(5));
+
+		assert_ok!(save_audit_log_with_sender(2));
+		System::set_block_number(5);
+		assert_noop!(save_audit_log_with_sender(2), Error::<Test>::DelegationExpired);
+
+		assert_ok!(save_audit_log_with_sender(3));
+		assert_ok!(Auditor::revoke_delegate(Origin::signed(1), "log-file-name".encode(), 3));
+		System::assert_last_event(Event::Auditor(crate::Event::DelegateRevoked(file_name("log-file-name"), 3)));
+		assert_noop!(save_audit_log_with_sender(3), Error::<Test>::AuditLogIdentifierCannotBeUsed);
+		assert_noop!(Auditor::revoke_delegate(Origin::signed(1), "log-file-name".encode(), 3), Error::<Test>::DelegationCantBeFound);
+	});
+}
+
+#[test]
+fn delegate_quota_counts_every_audit_log_of_a_batch() {
+	new_test_ext().execute_with(|| {
+		save_default_audit_log(1);
+		assert_ok!(Auditor::authorize_delegate(Origin::signed(1), "log-file-name".encode(), 2, None, Some(2)));
+
+		assert_noop!(
+			Auditor::save_audit_logs_batch(Origin::signed(2), vec![batch_entry("log-file-name", "first"), batch_entry("log-file-name", "second"), batch_entry("log-file-name", "third")]),
+			Error::<Test>::DelegateQuotaExceeded
+		);
+		assert_ok!(Auditor::save_audit_logs_batch(Origin::signed(2), vec![batch_entry("log-file-name", "first"), batch_entry("log-file-name", "second")]));
+	});
+}
+
+#[test]
+fn rate_limit_caps_audit_logs_per_block_on_a_log_file_and_for_a_reporter() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Writer));
+
+		assert_noop!(Auditor::set_log_rate_limit(Origin::signed(2), "log-file-name".encode(), None, Some(AuditLogRateLimit::new(Some(2), None))), Error::<Test>::NoRightsToManageAuditLogRoles);
+		assert_ok!(Auditor::set_log_rate_limit(Origin::signed(1), "log-file-name".encode(), None, Some(AuditLogRateLimit::new(Some(2), None))));
+		System::assert_last_event(Event::Auditor(crate::Event::AuditLogRateLimitSet(file_name("log-file-name"), None, Some(AuditLogRateLimit::new(Some(2), None)))));
+
+		assert_ok!(save_audit_log_with_sender(1));
+		assert_ok!(save_audit_log_with_sender(2));
+		assert_noop!(save_audit_log_with_sender(1), Error::<Test>::RateLimitExceeded);
+
+		// The audit logs are counted again on the next block
+		System::set_block_number(2);
+		assert_ok!(Auditor::set_log_rate_limit(Origin::signed(1), "log-file-name".encode(), Some(2), Some(AuditLogRateLimit::new(Some(1), None))));
+		assert_ok!(save_audit_log_with_sender(2));
+		assert_noop!(save_audit_log_with_sender(2), Error::<Test>::RateLimitExceeded);
+		assert_ok!(save_audit_log_with_sender(1));
+
+		assert_ok!(Auditor::set_log_rate_limit(Origin::signed(1), "log-file-name".encode(), None, None));
+		assert_ok!(save_audit_log_with_sender(1));
+		assert_ok!(save_audit_log_with_sender(1));
+		assert_eq!(Auditor::retrieve_log_rate_limit(file_name("log-file-name")), None);
+	});
+}
+
+#[test]
+fn rate_limit_caps_bytes_per_window_and_reports_the_remaining_quota() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(save_audit_log_with_sender(1));
+		let audit_log_size = Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY)[1].encoded_size() as u32;
+		assert_eq!(Auditor::remaining_rate_quota("log-file-name".encode(), 1), (None, None));
+
+		assert_ok!(Auditor::set_log_rate_limit(Origin::signed(1), "log-file-name".encode(), Some(1), Some(AuditLogRateLimit::new(None, Some(2 * audit_log_size)))));
+		assert_eq!(Auditor::remaining_rate_quota("log-file-name".encode(), 1), (None, Some(2 * audit_log_size)));
+
+		assert_ok!(save_audit_log_with_sender(1));
+		System::set_block_number(2);
+		assert_ok!(save_audit_log_with_sender(1));
+		assert_eq!(Auditor::remaining_rate_quota("log-file-name".encode(), 1), (None, Some(0)));
+		System::set_block_number(3);
+		assert_noop!(save_audit_log_with_sender(1), Error::<Test>::RateLimitExceeded);
+
+		// The bytes are counted again from the first block of the next window
+		System::set_block_number(RateLimitWindow::get());
+		assert_eq!(Auditor::remaining_rate_quota("log-file-name".encode(), 1), (None, Some(2 * audit_log_size)));
+		assert_ok!(Auditor::set_log_rate_limit(Origin::signed(1), "log-file-name".encode(), None, Some(AuditLogRateLimit::new(Some(1), None))));
+		assert_ok!(save_audit_log_with_sender(1));
+		assert_eq!(Auditor::remaining_rate_quota("log-file-name".encode(), 1), (Some(1), Some(audit_log_size)));
+	});
+}
+
+#[test]
+fn registered_reporter_has_the_fees_of_audit_logs_saved_within_its_quota_refunded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(register_payments_reporter(1));
+		let call = crate::Call::<Test>::save_audit_log {
+			log_file_name: "log-file-name".encode(),
+			log_claimed_date: None,
+			log_title: "log-title".encode(),
+			log_content: "content".encode(),
+			log_reporter_timestamp: "2021-10-08 17:30:00 UTC".encode(),
+		};
+		let check_quota = |who: u64| CheckAuditLogQuota::<Test>::new().validate(&who, &Call::Auditor(call.clone()), &Default::default(), 0);
+
+		// Fees are paid without a quota, or with one that limits nothing
+		assert_eq!(Auditor::audit_log_fee(&file_name("log-file-name"), &1), Pays::Yes);
+		assert_ok!(Auditor::set_log_rate_limit(Origin::signed(1), "log-file-name".encode(), Some(1), Some(AuditLogRateLimit::new(None, None))));
+		assert_eq!(Auditor::audit_log_fee(&file_name("log-file-name"), &1), Pays::Yes);
+		assert_ok!(Auditor::set_log_rate_limit(Origin::signed(1), "log-file-name".encode(), Some(1), Some(AuditLogRateLimit::new(Some(1), None))));
+
+		// The admin of a log file giving itself a quota does not waive its fees, only the feeless reporter origin does
+		assert_eq!(Auditor::audit_log_fee(&file_name("log-file-name"), &1), Pays::Yes);
+		assert_ok!(check_quota(1));
+		assert_noop!(Auditor::set_feeless_reporter(Origin::signed(1), 1, true), sp_runtime::traits::BadOrigin);
+		assert_ok!(Auditor::set_feeless_reporter(Origin::root(), 1, true));
+		System::assert_last_event(Event::Auditor(crate::Event::FeelessReporterSet(1, true)));
+		assert_ok!(check_quota(1));
+
+		// Audit logs failing to be saved pay their fee
+		let rejected = Auditor::save_audit_log(Origin::signed(1), "log-file-name".encode(), None, "log-title".encode(), vec![0; 65], "2021-10-08 17:30:00 UTC".encode()).unwrap_err();
+		assert_eq!((rejected.error, rejected.post_info.pays_fee), (Error::<Test>::AuditLogContentTooLong.into(), Pays::Yes));
+		assert_eq!(save_audit_log_with_sender(1).unwrap().pays_fee, Pays::No);
+		assert_eq!(Auditor::retrieve_audit_log(file_name("log-file-name"), TODAY).len(), 2);
+
+		// Over the quota the audit log is rejected before it reaches a block, and pays its fee when it is dispatched
+		assert_eq!(check_quota(1), Err(InvalidTransaction::Custom(AUDIT_LOG_QUOTA_EXCEEDED).into()));
+		let rejected = save_audit_log_with_sender(1).unwrap_err();
+		assert_eq!((rejected.error, rejected.post_info.pays_fee), (Error::<Test>::RateLimitExceeded.into(), Pays::Yes));
+
+		// Reporters which are not registered pay fees and are left to the dispatch
+		assert_ok!(Auditor::grant_log_role(Origin::signed(1), "log-file-name".encode(), 2, AuditLogRole::Writer));
+		assert_ok!(Auditor::set_log_rate_limit(Origin::signed(1), "log-file-name".encode(), Some(2), Some(AuditLogRateLimit::new(Some(1), None))));
+		assert_eq!(Auditor::audit_log_fee(&file_name("log-file-name"), &2), Pays::Yes);
+		assert_ok!(check_quota(2));
+
+		assert_ok!(Auditor::set_feeless_reporter(Origin::root(), 1, false));
+		assert_eq!(Auditor::audit_log_fee(&file_name("log-file-name"), &1), Pays::Yes);
+		assert_ok!(check_quota(1));
+	});
+}
+
+#[test]
+fn feeless_delegate_over_its_daily_quota_is_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		save_default_audit_log(1);
+		assert_ok!(register_payments_reporter(2));
+		assert_ok!(Auditor::set_feeless_reporter(Origin::root(), 2, true));
+		assert_ok!(Auditor::authorize_delegate(Origin::signed(1), "log-file-name".encode(), 2, None, Some(1)));
+		assert_ok!(Auditor::set_log_rate_limit(Origin::signed(1), "log-file-name".encode(), Some(2), Some(AuditLogRateLimit::new(Some(2), None))));
+		let call = crate::Call::<Test>::save_audit_log {
+			log_file_name: "log-file-name".encode(),
+			log_claimed_date: None,
+			log_title: "log-title".encode(),
+			log_content: "content".encode(),
+			log_reporter_timestamp: "2021-10-08 17:30:00 UTC".encode(),
+		};
+		let check_quota = || CheckAuditLogQuota::<Test>::new().validate(&2, &Call::Auditor(call.clone()), &Default::default(), 0);
+
+		assert_ok!(check_quota());
+		assert_eq!(save_audit_log_with_sender(2).unwrap().pays_fee, Pays::No);
+
+		// The rate limit quota is left, the daily quota of the delegation is not
+		assert_eq!(check_quota(), Err(InvalidTransaction::Custom(DELEGATE_QUOTA_EXCEEDED).into()));
+		let rejected = save_audit_log_with_sender(2).unwrap_err();
+		assert_eq!((rejected.error, rejected.post_info.pays_fee), (Error::<Test>::DelegateQuotaExceeded.into(), Pays::Yes));
+	});
+}
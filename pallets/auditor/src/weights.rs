@@ -0,0 +1,495 @@
+//! Weights for auditor-pallet.
+//!
+//! NOT GENERATED. These weights are conservative estimates written by hand, not benchmark
+//! results, and must be replaced with the output of the benchmark subcommand of the node, run on
+//! the reference hardware:
+//! `./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet auditor_pallet --extrinsic '*' --steps 50 --repeat 20 --output pallets/auditor/src/weights.rs`
+//!
+//! The database reads and writes are counted from the code of each extrinsic in its worst case,
+//! as listed above each function. The computation part is a rough upper bound, not a measure.
+//! Saving an audit log does not depend on the audit logs already saved for the day, its chain and
+//! Merkle tree being appended to in constant time.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use frame_support::sp_std::marker::PhantomData;
+
+/// Weight functions needed for auditor-pallet.
+pub trait WeightInfo {
+	fn save_audit_log(b: u32, ) -> Weight;
+	fn register_encryption_key() -> Weight;
+	fn start_key_epoch(r: u32, ) -> Weight;
+	fn register_reporter() -> Weight;
+	fn update_reporter() -> Weight;
+	fn retire_reporter() -> Weight;
+	fn set_registered_reporters_only() -> Weight;
+	fn authorize_delegate() -> Weight;
+	fn revoke_delegate() -> Weight;
+	fn set_log_rate_limit() -> Weight;
+	fn claim_log(o: u32, ) -> Weight;
+	fn open_log_for_ownership_claim(o: u32, c: u32, ) -> Weight;
+	fn cancel_ownership_claim() -> Weight;
+	fn replace_ownership_claim() -> Weight;
+	fn set_log_schema(a: u32, ) -> Weight;
+	fn clear_log_schema() -> Weight;
+	fn set_log_retention(d: u32, ) -> Weight;
+	fn grant_log_role(o: u32, ) -> Weight;
+	fn revoke_log_role(o: u32, ) -> Weight;
+	fn remove_owner(o: u32, ) -> Weight;
+	fn renounce_ownership(o: u32, ) -> Weight;
+	fn attest_audit_log() -> Weight;
+	fn delete_log_file() -> Weight;
+	fn redact_audit_log_entry(b: u32, ) -> Weight;
+	fn place_legal_hold() -> Weight;
+	fn release_legal_hold(d: u32, ) -> Weight;
+	fn set_feeless_reporter() -> Weight;
+	fn save_audit_logs_batch(n: u32, f: u32, b: u32, ) -> Weight;
+}
+
+/// Weights for auditor-pallet using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Timestamp Now: 1 read, 0 writes
+	// Auditor AuditLogRoleStorage: 1 read, 1 write
+	// Auditor AuditLogDeletedFileStorage: 1 read, 0 writes
+	// Auditor AuditLogDelegationStorage: 1 read, 1 write
+	// Auditor AuditLogRegisteredReportersOnlyStorage: 1 read, 0 writes
+	// Auditor AuditLogReporterStorage: 1 read, 0 writes
+	// Auditor AuditLogSchemaStorage: 1 read, 0 writes
+	// Auditor AuditLogFileRateLimitStorage: 1 read, 0 writes
+	// Auditor AuditLogFileRateUsageStorage: 1 read, 1 write
+	// Auditor AuditLogReporterRateLimitStorage: 1 read, 0 writes
+	// Auditor AuditLogReporterRateUsageStorage: 1 read, 1 write
+	// System Account: 1 read, 1 write
+	// Auditor AuditLogDepositStorage: 1 read, 1 write
+	// Auditor AuditLogEntryCountStorage: 1 read, 1 write
+	// Auditor AuditLogChainHeadStorage: 1 read, 1 write
+	// Auditor AuditLogEntryStorage: 0 reads, 1 write
+	// Auditor AuditLogChainLocationStorage: 0 reads, 1 write
+	// Auditor AuditLogMerkleStorage: 1 read, 1 write
+	// Auditor AuditLogRetentionStorage: 1 read, 0 writes
+	// Auditor AuditLogNextPruneDayStorage: 1 read, 1 write
+	// Auditor AuditLogPruneDayStorage: 0 reads, 1 write
+	// Auditor AuditLogPruneQueueStorage: 0 reads, 1 write
+	fn save_audit_log(b: u32, ) -> Weight {
+		(75_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+	}
+	// Auditor AuditLogEncryptionKeyStorage: 0 reads, 1 write
+	fn register_encryption_key() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogKeyEpochStorage: 1 read, 1 write
+	// Auditor AuditLogEncryptionKeyStorage: 1 read, 0 writes per r
+	fn start_key_epoch(r: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogReporterStorage: 1 read, 1 write
+	fn register_reporter() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogReporterStorage: 1 read, 1 write
+	fn update_reporter() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogReporterStorage: 2 reads, 1 write
+	fn retire_reporter() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogRegisteredReportersOnlyStorage: 0 reads, 1 write
+	fn set_registered_reporters_only() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogDelegationStorage: 0 reads, 1 write
+	fn authorize_delegate() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogDelegationStorage: 1 read, 1 write
+	fn revoke_delegate() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogFileRateLimitStorage or AuditLogReporterRateLimitStorage: 0 reads, 1 write
+	// Auditor AuditLogFileRateUsageStorage or AuditLogReporterRateUsageStorage: 0 reads, 1 write
+	fn set_log_rate_limit() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Auditor AuditLogOpenForClaimStorage: 2 reads, 1 write
+	// Auditor AuditLogRoleStorage: 1 read, 1 write
+	// Auditor AuditLogClaimExpiryStorage: 1 read, 1 write
+	fn claim_log(o: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogOpenForClaimStorage: 1 read, 1 write
+	// Auditor AuditLogClaimExpiryStorage: 1 read, 1 write
+	// Timestamp Now: 1 read, 0 writes
+	fn open_log_for_ownership_claim(o: u32, c: u32, ) -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add((60_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogOpenForClaimStorage: 2 reads, 1 write
+	// Auditor AuditLogClaimExpiryStorage: 1 read, 1 write
+	fn cancel_ownership_claim() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogOpenForClaimStorage: 2 reads, 2 writes
+	// Auditor AuditLogClaimExpiryStorage: 2 reads, 2 writes
+	// Timestamp Now: 1 read, 0 writes
+	fn replace_ownership_claim() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogSchemaStorage: 0 reads, 1 write
+	fn set_log_schema(a: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogSchemaStorage: 1 read, 1 write
+	fn clear_log_schema() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogRetentionStorage: 1 read, 1 write
+	// Auditor AuditLogEntryCountStorage: 1 read, 0 writes per d
+	// Auditor AuditLogPruneDayStorage: 1 read, 2 writes per d
+	// Auditor AuditLogPruneQueueStorage: 0 reads, 2 writes per d
+	// Auditor AuditLogNextPruneDayStorage: 1 read, 1 write per d
+	// Timestamp Now: 1 read, 0 writes per d
+	fn set_log_retention(d: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(d as Weight)))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 1 write
+	fn grant_log_role(o: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 1 write
+	fn revoke_log_role(o: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 1 write
+	fn remove_owner(o: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 1 write
+	fn renounce_ownership(o: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogEntryCountStorage: 1 read, 0 writes
+	fn attest_audit_log() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 1 write
+	// Auditor AuditLogLegalHoldStorage: 1 read, 0 writes
+	// Auditor AuditLogOpenForClaimStorage: 1 read, 1 write
+	// Auditor AuditLogClaimExpiryStorage: 1 read, 1 write
+	// Auditor AuditLogChainHeadStorage: 0 reads, 1 write
+	// Auditor AuditLogSchemaStorage: 0 reads, 1 write
+	// Auditor AuditLogRetentionStorage: 0 reads, 1 write
+	// Auditor AuditLogKeyEpochStorage: 0 reads, 1 write
+	// Auditor AuditLogRegisteredReportersOnlyStorage: 0 reads, 1 write
+	// Auditor AuditLogFileRateLimitStorage: 0 reads, 1 write
+	// Auditor AuditLogFileRateUsageStorage: 0 reads, 1 write
+	// Auditor AuditLogDeletedFileStorage: 0 reads, 1 write
+	fn delete_log_file() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 0 writes
+	// Auditor AuditLogLegalHoldStorage: 1 read, 0 writes
+	// Auditor AuditLogEntryStorage: 1 read, 1 write
+	// Timestamp Now: 1 read, 0 writes
+	fn redact_audit_log_entry(b: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogLegalHoldStorage: 1 read, 1 write
+	fn place_legal_hold() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogLegalHoldStorage: 1 read, 1 write
+	// Auditor AuditLogRetentionStorage: 1 read, 0 writes
+	// Auditor AuditLogEntryCountStorage: 1 read, 0 writes per d
+	// Auditor AuditLogPruneDayStorage: 1 read, 2 writes per d
+	// Auditor AuditLogPruneQueueStorage: 0 reads, 2 writes per d
+	// Auditor AuditLogNextPruneDayStorage: 1 read, 1 write per d
+	// Timestamp Now: 1 read, 0 writes per d
+	fn release_legal_hold(d: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(d as Weight)))
+	}
+	// Auditor AuditLogFeelessReporterStorage: 0 reads, 1 write
+	fn set_feeless_reporter() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Auditor AuditLogRoleStorage: 1 read, 1 write per f
+	// Auditor AuditLogDeletedFileStorage: 1 read, 0 writes per f
+	// Auditor AuditLogRegisteredReportersOnlyStorage: 1 read, 0 writes per f
+	// Auditor AuditLogReporterStorage: 1 read, 0 writes per f
+	// Auditor AuditLogSchemaStorage: 1 read, 0 writes per f
+	// Timestamp Now: 1 read, 0 writes per n
+	// Auditor AuditLogDelegationStorage: 1 read, 1 write per n
+	// Auditor AuditLogFileRateLimitStorage: 1 read, 0 writes per n
+	// Auditor AuditLogFileRateUsageStorage: 1 read, 1 write per n
+	// Auditor AuditLogReporterRateLimitStorage: 1 read, 0 writes per n
+	// Auditor AuditLogReporterRateUsageStorage: 1 read, 1 write per n
+	// System Account: 1 read, 1 write per n
+	// Auditor AuditLogDepositStorage: 1 read, 1 write per n
+	// Auditor AuditLogEntryCountStorage: 1 read, 1 write per n
+	// Auditor AuditLogChainHeadStorage: 1 read, 1 write per n
+	// Auditor AuditLogEntryStorage: 0 reads, 1 write per n
+	// Auditor AuditLogChainLocationStorage: 0 reads, 1 write per n
+	// Auditor AuditLogMerkleStorage: 1 read, 1 write per n
+	// Auditor AuditLogRetentionStorage: 1 read, 0 writes per n
+	// Auditor AuditLogNextPruneDayStorage: 1 read, 1 write per n
+	// Auditor AuditLogPruneDayStorage: 0 reads, 1 write per n
+	// Auditor AuditLogPruneQueueStorage: 0 reads, 1 write per n
+	fn save_audit_logs_batch(n: u32, f: u32, b: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((50_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((25_000_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads((13 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(f as Weight)))
+			.saturating_add(T::DbWeight::get().writes((13 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(f as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn save_audit_log(b: u32, ) -> Weight {
+		(75_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
+	}
+	fn register_encryption_key() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn start_key_epoch(r: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((1_000_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn register_reporter() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn update_reporter() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn retire_reporter() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_registered_reporters_only() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn authorize_delegate() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn revoke_delegate() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_log_rate_limit() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn claim_log(o: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn open_log_for_ownership_claim(o: u32, c: u32, ) -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add((60_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn cancel_ownership_claim() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn replace_ownership_claim() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn set_log_schema(a: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn clear_log_schema() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_log_retention(d: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(d as Weight)))
+	}
+	fn grant_log_role(o: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn revoke_log_role(o: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_owner(o: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn renounce_ownership(o: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn attest_audit_log() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+	}
+	fn delete_log_file() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	fn redact_audit_log_entry(b: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn place_legal_hold() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn release_legal_hold(d: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((12_000_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(d as Weight)))
+	}
+	fn set_feeless_reporter() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn save_audit_logs_batch(n: u32, f: u32, b: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add((50_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((25_000_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads((13 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(f as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((13 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(f as Weight)))
+	}
+}
@@ -100,10 +100,14 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	// Bumped with every change to the storage, the pallet migrations only running on a new
+	//   spec version.
+	spec_version: 102,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Bumped with every change to the calls or the signed extensions, as they change how
+	//   transactions are encoded.
+	transaction_version: 2,
 };
 
 /// This determines the average expected block time that we are targeting.
@@ -279,8 +283,59 @@ impl pallet_template::Config for Runtime {
 	type Event = Event;
 }
 
+parameter_types! {
+	/// Ownership claims on an audit log stay open for a day.
+	pub const ClaimWindow: BlockNumber = DAYS;
+	pub const MaxFileNameLen: u32 = 128;
+	pub const MaxDateLen: u32 = 64;
+	pub const MaxTitleLen: u32 = 256;
+	pub const MaxContentLen: u32 = 4 * 1024;
+	pub const MaxLocatorLen: u32 = 256;
+	pub const MaxOwners: u32 = 32;
+	pub const MaxEntriesPerDay: u32 = 100_000;
+	pub const MaxClaimsExpiringPerBlock: u32 = 64;
+	pub const MaxTags: u32 = 16;
+	pub const MaxTagLen: u32 = 64;
+	pub const MaxAttributes: u32 = 16;
+	pub const MaxAttributeKeyLen: u32 = 64;
+	pub const MaxAttributeValueLen: u32 = 256;
+	pub const MaxBatchSize: u32 = 500;
+	pub const MaxReaders: u32 = 32;
+	pub const MaxReporterFieldLen: u32 = 64;
+	/// Rate limits count the bytes of the audit logs saved over a day of blocks.
+	pub const AuditLogRateLimitWindow: BlockNumber = DAYS;
+	/// Keeping an audit log on-chain costs a fixed deposit plus a deposit per byte.
+	pub const AuditLogDepositPerItem: Balance = 100_000;
+	pub const AuditLogDepositPerByte: Balance = 1_000;
+}
+
 impl auditor_pallet::Config for Runtime {
 	type Event = Event;
+	type ClaimWindow = ClaimWindow;
+	type MaxFileNameLen = MaxFileNameLen;
+	type MaxDateLen = MaxDateLen;
+	type MaxTitleLen = MaxTitleLen;
+	type MaxContentLen = MaxContentLen;
+	type MaxLocatorLen = MaxLocatorLen;
+	type MaxOwners = MaxOwners;
+	type MaxEntriesPerDay = MaxEntriesPerDay;
+	type MaxClaimsExpiringPerBlock = MaxClaimsExpiringPerBlock;
+	type MaxTags = MaxTags;
+	type MaxTagLen = MaxTagLen;
+	type MaxAttributes = MaxAttributes;
+	type MaxAttributeKeyLen = MaxAttributeKeyLen;
+	type MaxAttributeValueLen = MaxAttributeValueLen;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxReaders = MaxReaders;
+	type MaxReporterFieldLen = MaxReporterFieldLen;
+	type RateLimitWindow = AuditLogRateLimitWindow;
+	type Currency = Balances;
+	type DepositPerItem = AuditLogDepositPerItem;
+	type DepositPerByte = AuditLogDepositPerByte;
+	type LegalHoldOrigin = frame_system::EnsureRoot<AccountId>;
+	type PrivacyOfficerOrigin = frame_system::EnsureRoot<AccountId>;
+	type FeelessReporterOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = auditor_pallet::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -318,6 +373,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
+	auditor_pallet::feeless::CheckAuditLogQuota<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
@@ -462,6 +518,58 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl auditor_runtime_api::AuditorApi<Block, auditor_pallet::AuditLog<Runtime>, Hash, auditor_pallet::Severity, auditor_pallet::AuditLogSchema<Runtime>, AccountId> for Runtime {
+		fn retrieve_audit_logs(log_file_name: Vec<u8>, log_day: u32) -> Vec<auditor_pallet::AuditLog<Runtime>> {
+			Auditor::retrieve_audit_logs(log_file_name, log_day)
+		}
+
+		fn retrieve_audit_logs_filtered(
+			log_file_name: Vec<u8>,
+			log_day: u32,
+			min_severity: Option<auditor_pallet::Severity>,
+			tag: Option<Vec<u8>>,
+			attribute: Option<(Vec<u8>, Vec<u8>)>,
+		) -> Vec<auditor_pallet::AuditLog<Runtime>> {
+			Auditor::retrieve_audit_logs_filtered(log_file_name, log_day, min_severity, tag, attribute)
+		}
+
+		fn verify_audit_log_content(log_file_name: Vec<u8>, log_day: u32, entry_index: u32, payload: Vec<u8>) -> bool {
+			Auditor::verify_audit_log_content(log_file_name, log_day, entry_index, payload)
+		}
+
+		fn retrieve_audit_log_chain(log_file_name: Vec<u8>, from: u64, to: u64) -> Vec<auditor_pallet::AuditLog<Runtime>> {
+			Auditor::retrieve_audit_log_chain(log_file_name, from, to)
+		}
+
+		fn verify_chain(log_file_name: Vec<u8>, from: u64, to: u64) -> bool {
+			Auditor::verify_chain(log_file_name, from, to)
+		}
+
+		fn audit_log_entry_proof(log_file_name: Vec<u8>, log_day: u32, entry_index: u32) -> Option<(Hash, Vec<Hash>, Hash)> {
+			Auditor::audit_log_entry_proof(log_file_name, log_day, entry_index)
+		}
+
+		fn retrieve_log_schema(log_file_name: Vec<u8>) -> Option<auditor_pallet::AuditLogSchema<Runtime>> {
+			Auditor::retrieve_active_log_schema(log_file_name)
+		}
+
+		fn audit_logs_due_for_pruning(within_days: u32) -> Vec<(Vec<u8>, u32, u32)> {
+			Auditor::audit_logs_due_for_pruning(within_days)
+		}
+
+		fn reporter_label(reporter: AccountId) -> Option<Vec<u8>> {
+			Auditor::reporter_label(reporter)
+		}
+
+		fn remaining_rate_quota(log_file_name: Vec<u8>, reporter: AccountId) -> (Option<u32>, Option<u32>) {
+			Auditor::remaining_rate_quota(log_file_name, reporter)
+		}
+
+		fn log_day_count(log_file_name: Vec<u8>) -> u32 {
+			Auditor::log_day_count(log_file_name)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -478,7 +586,7 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_balances, Balances);
 			list_benchmark!(list, extra, pallet_timestamp, Timestamp);
 			list_benchmark!(list, extra, pallet_template, TemplateModule);
-			list_benchmark!(list, extra, pallet_template, Auditor);
+			list_benchmark!(list, extra, auditor_pallet, Auditor);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -513,10 +621,27 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
 			add_benchmark!(params, batches, pallet_template, TemplateModule);
-			add_benchmark!(params, batches, pallet_template, Auditor);
+			add_benchmark!(params, batches, auditor_pallet, Auditor);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use auditor_pallet::weights::WeightInfo;
+	use frame_support::weights::DispatchClass;
+
+	#[test]
+	fn largest_audit_log_batch_fits_in_an_extrinsic() {
+		// Every audit log on a log file of its own, the batch as long as a normal extrinsic can be
+		let batch_size = MaxBatchSize::get();
+		let batch_len = *BlockLength::get().max.get(DispatchClass::Normal);
+		let weight = auditor_pallet::weights::SubstrateWeight::<Runtime>::save_audit_logs_batch(batch_size, batch_size, batch_len);
+		let max_extrinsic = BlockWeights::get().get(DispatchClass::Normal).max_extrinsic.expect("normal extrinsics have a maximum weight");
+		assert!(weight <= max_extrinsic, "batch weight {} over the extrinsic maximum {}", weight, max_extrinsic);
+	}
+}